@@ -0,0 +1,69 @@
+//! Generates the solution registry that is compiled into the main binary.
+//!
+//! Every `src/bin/YYYY_DD.rs` is included as a module of `src/main.rs`, so that `cargo all` and
+//! `cargo time` can call each day's `SOLUTION` in-process instead of spawning `cargo run`.
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut days: Vec<String> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let stem = name.strip_suffix(".rs")?;
+                    let (year, day) = stem.split_once('_')?;
+                    let is_day = year.len() == 4
+                        && day.len() == 2
+                        && stem.bytes().all(|b| b == b'_' || b.is_ascii_digit());
+                    is_day.then(|| stem.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort_unstable();
+
+    // the registry is left empty for tests (the day binaries run their own tests) and for
+    // dhat builds (every day declares its own global allocator).
+    let cfg_registry = r#"#[cfg(not(any(test, feature = "dhat-heap")))]"#;
+    let cfg_empty = r#"#[cfg(any(test, feature = "dhat-heap"))]"#;
+
+    let mut out = String::new();
+
+    for day in &days {
+        let path = bin_dir.join(format!("{day}.rs"));
+        writeln!(out, "{cfg_registry}").unwrap();
+        writeln!(out, "#[allow(dead_code)]").unwrap();
+        writeln!(out, "#[path = {:?}]", path.display().to_string()).unwrap();
+        writeln!(out, "mod day_{day};").unwrap();
+    }
+
+    let entries: Vec<String> = days
+        .iter()
+        .map(|day| format!("day_{day}::SOLUTION"))
+        .collect();
+
+    writeln!(out, "{cfg_registry}").unwrap();
+    writeln!(
+        out,
+        "pub static SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[{}];",
+        entries.join(", ")
+    )
+    .unwrap();
+    writeln!(out, "{cfg_empty}").unwrap();
+    writeln!(
+        out,
+        "pub static SOLUTIONS: &[advent_of_code::template::runner::Solution] = &[];"
+    )
+    .unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), out).unwrap();
+}
@@ -1,15 +1,18 @@
 use std::collections::HashMap;
 
+use advent_of_code::parse::{extract, lines};
+
 advent_of_code::solution!(1);
 
+fn parse_lists(input: &str) -> (Vec<i32>, Vec<i32>) {
+    lines(input, extract::<(i32, i32)>)
+        .expect("invalid location ids")
+        .into_iter()
+        .unzip()
+}
+
 pub fn part_one(input: &str) -> Option<u32> {
-    let (mut l, mut r): (Vec<i32>, Vec<i32>) = input
-        .lines()
-        .map(|l| {
-            let mut nums = l.split_whitespace().map(|n| n.parse::<i32>().unwrap());
-            (nums.next().unwrap(), nums.next().unwrap())
-        })
-        .unzip();
+    let (mut l, mut r) = parse_lists(input);
 
     l.sort();
     r.sort();
@@ -19,13 +22,7 @@ pub fn part_one(input: &str) -> Option<u32> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let (l, r): (Vec<i32>, Vec<i32>) = input
-        .lines()
-        .map(|l| {
-            let mut nums = l.split_whitespace().map(|n| n.parse::<i32>().unwrap());
-            (nums.next().unwrap(), nums.next().unwrap())
-        })
-        .unzip();
+    let (l, r) = parse_lists(input);
 
     let mut r_count: HashMap<i32, usize> = HashMap::new();
 
@@ -50,13 +47,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(11));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(31));
     }
 }
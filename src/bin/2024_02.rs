@@ -1,3 +1,5 @@
+use advent_of_code::parse::ints;
+
 advent_of_code::solution!(2);
 
 fn is_safe(levels: &[i32]) -> bool {
@@ -18,10 +20,7 @@ pub fn part_one(input: &str) -> Option<u32> {
     let count = input
         .lines()
         .filter(|report| {
-            let levels: Vec<i32> = report
-                .split_whitespace()
-                .map(|n| n.parse::<i32>().expect("Invalid number"))
-                .collect();
+            let levels: Vec<i32> = ints(report).collect();
 
             is_safe(&levels)
         })
@@ -34,10 +33,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     let count = input
         .lines()
         .filter(|report| {
-            let levels: Vec<i32> = report
-                .split_whitespace()
-                .map(|n| n.parse::<i32>().expect("Invalid number"))
-                .collect();
+            let levels: Vec<i32> = ints(report).collect();
 
             if is_safe(&levels) {
                 return true;
@@ -64,13 +60,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4));
     }
 }
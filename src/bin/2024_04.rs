@@ -0,0 +1,69 @@
+use advent_of_code::geometry::{Direction8, Point, Vector};
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(4);
+
+fn matches_word(grid: &Grid<char>, needle: &[char], start: Point, step: Vector) -> bool {
+    let mut cells = grid.ray(start, step).map(|(_, &c)| c);
+    needle.iter().all(|&c| cells.next() == Some(c))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = Grid::parse(input, |c| c);
+    let needle: Vec<char> = "XMAS".chars().collect();
+    let mut count = 0;
+
+    for point in grid.positions(|&c| c == needle[0]) {
+        for direction in Direction8::ALL {
+            if matches_word(&grid, &needle, point, direction.offset()) {
+                count += 1;
+            }
+        }
+    }
+
+    Some(count)
+}
+
+fn matches_mas(seq: &[char; 3]) -> bool {
+    *seq == ['M', 'A', 'S'] || *seq == ['S', 'A', 'M']
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = Grid::parse(input, |c| c);
+    let mut count = 0;
+
+    for point in grid.positions(|&c| c == 'A') {
+        let at = |dx, dy| grid.get(point + Point::new(dx, dy)).copied();
+
+        let (Some(c1), Some(c3), Some(c4), Some(c5)) = (at(-1, -1), at(1, 1), at(1, -1), at(-1, 1))
+        else {
+            continue;
+        };
+
+        let seq1 = [c1, 'A', c3];
+        let seq2 = [c4, 'A', c5];
+
+        if matches_mas(&seq1) && matches_mas(&seq2) {
+            count += 1;
+        }
+    }
+
+    Some(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(9));
+    }
+}
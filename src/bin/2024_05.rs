@@ -1,26 +1,34 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
+use advent_of_code::parse::{lines, sections, ParseError, Pattern};
+
 advent_of_code::solution!(5);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (rules_section, updates_section) = input.split_once("\n\n")?;
+/// A page that must be printed before another page.
+type Rule = (u32, u32);
 
-    let mut rules: Vec<(u32, u32)> = Vec::new();
+fn parse(input: &str) -> Option<(Vec<Rule>, Vec<Vec<u32>>)> {
+    let mut sections = sections(input);
+    let (rules_section, updates_section) = (sections.next()?, sections.next()?);
 
-    for rule in rules_section.lines() {
-        let (x, y) = rule.split_once("|")?;
-        rules.push((x.parse().unwrap(), y.parse().unwrap()));
-    }
+    let rule = Pattern::new("{}|{}");
+    let rules = rule.parse_lines(rules_section).expect("invalid rules");
 
-    let updates: Vec<Vec<u32>> = updates_section
-        .lines()
-        .map(|l| {
-            l.trim()
-                .split(',')
-                .map(|n| n.parse::<u32>().ok())
-                .collect::<Option<Vec<u32>>>()
-        })
-        .collect::<Option<Vec<Vec<u32>>>>()?;
+    let updates = lines(updates_section, |l| {
+        l.split(',')
+            .map(|page| {
+                page.parse()
+                    .map_err(|_| ParseError::new(l, format!("`{page}` is not a page number")))
+            })
+            .collect()
+    })
+    .expect("invalid updates");
+
+    Some((rules, updates))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (rules, updates) = parse(input)?;
 
     let mut sum = 0;
 
@@ -53,24 +61,7 @@ fn is_ordered(update: &[u32], rules: &[(u32, u32)]) -> bool {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let (rules_section, updates_section) = input.split_once("\n\n")?;
-
-    let mut rules: Vec<(u32, u32)> = Vec::new();
-
-    for rule in rules_section.lines() {
-        let (x, y) = rule.split_once("|")?;
-        rules.push((x.parse().unwrap(), y.parse().unwrap()));
-    }
-
-    let updates: Vec<Vec<u32>> = updates_section
-        .lines()
-        .map(|l| {
-            l.trim()
-                .split(',')
-                .map(|n| n.parse::<u32>().ok())
-                .collect::<Option<Vec<u32>>>()
-        })
-        .collect::<Option<Vec<Vec<u32>>>>()?;
+    let (rules, updates) = parse(input)?;
 
     let mut sum = 0;
 
@@ -146,13 +137,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(143));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(123));
     }
 }
@@ -0,0 +1,91 @@
+use std::collections::HashSet;
+
+use advent_of_code::geometry::{Direction, Point};
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(6);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let map = Grid::parse(input, |c| c);
+
+    let (mut position, mut direction) = find_guard(&map).expect("No guard found");
+
+    let mut visited = HashSet::new();
+    visited.insert(position);
+
+    loop {
+        let next = position + direction.offset();
+
+        match map.get(next) {
+            None => break,
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => {
+                position = next;
+                visited.insert(position);
+            }
+        }
+    }
+
+    Some(visited.len() as u32)
+}
+
+fn find_guard(map: &Grid<char>) -> Option<(Point, Direction)> {
+    let is_guard = |c: &char| "^>v<".contains(*c);
+    let position = map.find(is_guard)?;
+    let direction = Direction::try_from(map[position]).ok()?;
+    Some((position, direction))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let map = Grid::parse(input, |c| c);
+
+    let (start, _) = find_guard(&map).expect("No guard found");
+    let mut valid_pos_count = 0;
+
+    for point in map.points() {
+        if map[point] == '#' || point == start {
+            continue;
+        }
+
+        let mut map = map.clone();
+        map[point] = '#';
+
+        if is_stuck(&map) {
+            valid_pos_count += 1;
+        }
+    }
+    Some(valid_pos_count)
+}
+
+fn is_stuck(map: &Grid<char>) -> bool {
+    let (mut position, mut direction) = find_guard(map).expect("No guard found");
+    let mut visited = HashSet::new();
+
+    while visited.insert((position, direction)) {
+        let next = position + direction.offset();
+
+        match map.get(next) {
+            None => return false,
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => position = next,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(41));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(6));
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::{HashMap, HashSet};
+
+use advent_of_code::geometry::Point;
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(8);
+
+fn parse(input: &str) -> (Grid<char>, HashMap<char, Vec<Point>>) {
+    let grid = Grid::parse(input, |c| c);
+
+    let mut freq_map: HashMap<char, Vec<Point>> = HashMap::new();
+    for (point, &c) in grid.iter() {
+        if c != '.' {
+            freq_map.entry(c).or_default().push(point);
+        }
+    }
+
+    (grid, freq_map)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (grid, freq_map) = parse(input);
+    let mut antinodes = HashSet::new();
+
+    for positions in freq_map.values() {
+        let n = positions.len();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let (p1, p2) = (positions[i], positions[j]);
+
+                antinodes.insert(p1 + (p1 - p2));
+
+                antinodes.insert(p2 + (p2 - p1));
+
+                let c_third = p1 + p1 + p2;
+                if c_third.x % 3 == 0 && c_third.y % 3 == 0 {
+                    antinodes.insert(Point::new(c_third.x / 3, c_third.y / 3));
+                }
+
+                let c_twothirds = p1 + p2 + p2;
+                if c_twothirds.x % 3 == 0 && c_twothirds.y % 3 == 0 {
+                    antinodes.insert(Point::new(c_twothirds.x / 3, c_twothirds.y / 3));
+                }
+            }
+        }
+    }
+
+    antinodes.retain(|&point| grid.contains(point));
+
+    Some(antinodes.len() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (grid, freq_map) = parse(input);
+    let mut antinodes = HashSet::new();
+
+    for positions in freq_map.values() {
+        if positions.len() < 2 {
+            continue;
+        }
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                let (p1, p2) = (positions[i], positions[j]);
+
+                let delta = p2 - p1;
+                let g = gcd(delta.x, delta.y);
+                let step = Point::new(delta.x / g, delta.y / g);
+
+                antinodes.extend(grid.ray(p1, step).map(|(point, _)| point));
+                antinodes.extend(grid.ray(p1, -step).map(|(point, _)| point));
+            }
+        }
+    }
+    Some(antinodes.len() as u32)
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(14));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(34));
+    }
+}
@@ -0,0 +1,73 @@
+use advent_of_code::geometry::Point;
+use advent_of_code::grid::Grid;
+use advent_of_code::search::{bfs, Search};
+
+advent_of_code::solution!(10);
+
+fn parse(input: &str) -> Grid<u8> {
+    Grid::parse(input, |c| c.to_digit(10).unwrap() as u8)
+}
+
+/// All trails from a trailhead, each step goes up by exactly one.
+fn trails(grid: &Grid<u8>, trailhead: Point) -> Search<Point, usize> {
+    bfs([trailhead], |&point| {
+        let height = grid[point];
+        grid.neighbours_4(point)
+            .filter(move |(_, &next_h)| next_h == height + 1)
+            .map(|(next, _)| next)
+    })
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let grid = parse(input);
+
+    let score = grid
+        .positions(|&h| h == 0)
+        .map(|trailhead| {
+            let trails = trails(&grid, trailhead);
+            trails
+                .distances()
+                .keys()
+                .filter(|&&point| grid[point] == 9)
+                .count()
+        })
+        .sum();
+
+    Some(score)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let grid = parse(input);
+
+    // every trail has the same length, so each distinct trail is a shortest path.
+    let rating = grid
+        .positions(|&h| h == 0)
+        .map(|trailhead| {
+            trails(&grid, trailhead)
+                .path_counts()
+                .into_iter()
+                .filter(|&(point, _)| grid[point] == 9)
+                .map(|(_, count)| count)
+                .sum::<usize>()
+        })
+        .sum();
+
+    Some(rating)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(36));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(81));
+    }
+}
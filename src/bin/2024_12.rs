@@ -0,0 +1,36 @@
+use advent_of_code::grid::Grid;
+use advent_of_code::region::Regions;
+
+advent_of_code::solution!(12, parse = parse);
+
+fn parse(input: &str) -> Regions {
+    let grid = Grid::parse(input, |c| c);
+    Regions::new(&grid, |a, b| a == b)
+}
+
+pub fn part_one(regions: &Regions) -> Option<usize> {
+    Some(regions.iter().map(|r| r.area * r.perimeter).sum())
+}
+
+pub fn part_two(regions: &Regions) -> Option<usize> {
+    Some(regions.iter().map(|r| r.area * r.sides).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let result = part_one(&input);
+        assert_eq!(result, Some(1930));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let result = part_two(&input);
+        assert_eq!(result, Some(1206));
+    }
+}
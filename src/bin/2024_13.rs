@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use advent_of_code::parse::{extract, sections};
 
 advent_of_code::solution!(13);
 
@@ -14,13 +14,8 @@ fn solve(x1: i64, x2: i64, y1: i64, y2: i64, z1: i64, z2: i64) -> i64 {
 pub fn part_one(input: &str) -> Option<u32> {
     let mut result = 0;
 
-    for l in input.split("\n\n") {
-        let (x1, x2, y1, y2, z1, z2) = l
-            .split(|c: char| !c.is_ascii_digit())
-            .filter(|w| !w.is_empty())
-            .map(|w| w.parse().unwrap())
-            .collect_tuple()
-            .unwrap();
+    for l in sections(input) {
+        let [x1, x2, y1, y2, z1, z2] = extract(l).expect("invalid claw machine");
 
         result += solve(x1, x2, y1, y2, z1, z2);
     }
@@ -30,13 +25,8 @@ pub fn part_one(input: &str) -> Option<u32> {
 
 pub fn part_two(input: &str) -> Option<usize> {
     let mut result = 0;
-    for l in input.split("\n\n") {
-        let (x1, x2, y1, y2, z1, z2) = l
-            .split(|c: char| !c.is_ascii_digit())
-            .filter(|w| !w.is_empty())
-            .map(|w| w.parse().unwrap())
-            .collect_tuple()
-            .unwrap();
+    for l in sections(input) {
+        let [x1, x2, y1, y2, z1, z2] = extract(l).expect("invalid claw machine");
         result += solve(x1, x2, y1, y2, z1 + 10000000000000, z2 + 10000000000000);
     }
 
@@ -49,13 +39,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(480));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(875318608908));
     }
 }
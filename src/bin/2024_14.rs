@@ -1,94 +1,44 @@
-advent_of_code::solution!(14);
-
-pub struct Point {
-    x: isize,
-    y: isize,
-}
+use advent_of_code::geometry::{Point, Vector};
+use advent_of_code::parse::Pattern;
 
-impl Point {
-    fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-}
+advent_of_code::solution!(14);
 
 pub struct Robot {
     pub position: Point,
-    pub velocity: Point,
+    pub velocity: Vector,
 }
 
 pub fn parse(input: &str) -> Vec<Robot> {
-    input
-        .lines()
-        .map(|line| {
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            let mut p = parts[0][2..]
-                .trim_end_matches(',')
-                .split(',')
-                .map(|n| n.parse::<isize>().unwrap());
-            let mut v = parts[1][2..]
-                .split(',')
-                .map(|n| n.parse::<isize>().unwrap());
-
-            Robot {
-                position: Point::new(p.next().unwrap(), p.next().unwrap()),
-                velocity: Point::new(v.next().unwrap(), v.next().unwrap()),
-            }
+    Pattern::new("p={},{} v={},{}")
+        .parse_lines::<[isize; 4]>(input)
+        .expect("invalid robot")
+        .into_iter()
+        .map(|[px, py, vx, vy]| Robot {
+            position: Point::new(px, py),
+            velocity: Vector::new(vx, vy),
         })
         .collect()
 }
 
 pub fn part_one(input: &str) -> Option<u32> {
-    const WIDTH: usize = 101;
-    const HEIGHT: usize = 103;
-    const SECONDS: usize = 100;
-
-    let robots = parse(input);
-    let mut robots_data: Vec<((usize, usize), (isize, isize))> = robots
-        .iter()
-        .map(|r| {
-            (
-                (r.position.x as usize, r.position.y as usize),
-                (r.velocity.x, r.velocity.y),
-            )
-        })
-        .collect();
-
-    for _ in 0..SECONDS {
-        robots_data = robots_data
-            .iter()
-            .map(|&((x, y), (vx, vy))| {
-                let new_x = ((x as isize + vx).rem_euclid(WIDTH as isize)) as usize;
-                let new_y = ((y as isize + vy).rem_euclid(HEIGHT as isize)) as usize;
-                ((new_x, new_y), (vx, vy))
-            })
-            .collect();
-    }
-
-    let mut tiles = vec![vec![0; WIDTH]; HEIGHT];
-    for &((x, y), _) in &robots_data {
-        tiles[y][x] += 1;
-    }
+    const WIDTH: isize = 101;
+    const HEIGHT: isize = 103;
+    const SECONDS: isize = 100;
 
     let mid_x = WIDTH / 2;
     let mid_y = HEIGHT / 2;
 
     let mut quadrants = [0, 0, 0, 0];
-    for (y, _) in tiles.iter().enumerate().take(HEIGHT) {
-        for x in 0..WIDTH {
-            if x == mid_x || y == mid_y {
-                continue;
-            }
-
-            if x < mid_x && y < mid_y {
-                quadrants[0] += tiles[y][x];
-            } else if x > mid_x && y < mid_y {
-                quadrants[1] += tiles[y][x];
-            } else if x < mid_x && y > mid_y {
-                quadrants[2] += tiles[y][x];
-            } else if x > mid_x && y > mid_y {
-                quadrants[3] += tiles[y][x];
-            }
+    for robot in parse(input) {
+        let moved = robot.position + robot.velocity * SECONDS;
+        let position = Point::new(moved.x.rem_euclid(WIDTH), moved.y.rem_euclid(HEIGHT));
+
+        if position.x == mid_x || position.y == mid_y {
+            continue;
         }
+
+        let quadrant = usize::from(position.x > mid_x) + 2 * usize::from(position.y > mid_y);
+        quadrants[quadrant] += 1;
     }
 
     Some(quadrants.iter().product())
@@ -165,13 +115,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(5253));
     }
 }
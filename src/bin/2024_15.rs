@@ -0,0 +1,183 @@
+use advent_of_code::geometry::{Direction, Point};
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(15);
+
+fn parse(input: &str, part: u8) -> (Grid<u8>, Vec<Direction>, Point) {
+    let (map, moves) = input.split_once("\n\n").unwrap();
+
+    let map = if part == 1 {
+        map.to_string()
+    } else {
+        map.chars()
+            .map(|c| match c {
+                '#' => "##",
+                'O' => "[]",
+                '.' => "..",
+                '@' => "@.",
+                '\n' => "\n",
+                _ => unreachable!(),
+            })
+            .collect()
+    };
+
+    let mut grid = Grid::parse(&map, |c| c as u8);
+    let robot = grid.find(|&b| b == b'@').unwrap();
+    grid[robot] = b'.';
+
+    (
+        grid,
+        moves
+            .lines()
+            .flat_map(|line| line.chars())
+            .map(|c| Direction::try_from(c).unwrap())
+            .collect(),
+        robot,
+    )
+}
+
+fn coordinates(grid: &Grid<u8>) -> usize {
+    grid.positions(|&b| b == b'O' || b == b'[')
+        .map(|point| point.y * 100 + point.x)
+        .sum::<isize>() as usize
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let (mut grid, moves, mut robot) = parse(input, 1);
+
+    for direction in moves {
+        let step = direction.offset();
+        let next = robot + step;
+
+        match grid[next] {
+            b'.' => {
+                robot = next;
+            }
+            b'O' => {
+                let mut boxes = vec![next];
+                let mut path = next + step;
+
+                while grid[path] == b'O' {
+                    boxes.push(path);
+                    path += step;
+                }
+
+                if grid[path] == b'.' {
+                    for &b in boxes.iter().rev() {
+                        grid[b + step] = b'O';
+                        grid[b] = b'.';
+                    }
+
+                    robot = next;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(coordinates(&grid))
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let (mut grid, moves, mut robot) = parse(input, 2);
+
+    for direction in moves {
+        let step = direction.offset();
+        let next = robot + step;
+
+        match grid[next] {
+            b'.' => {
+                robot = next;
+            }
+            side @ b'[' | side @ b']' => {
+                let mut boxes = vec![next];
+
+                if side == b'[' {
+                    boxes.push(next + Direction::Right.offset());
+                } else {
+                    boxes.push(next + Direction::Left.offset());
+                }
+
+                let mut blocked = false;
+
+                match direction {
+                    Direction::Up | Direction::Down => {
+                        let mut current = boxes.clone();
+
+                        while current.len() > 1 {
+                            let mut next = Vec::new();
+
+                            for b in current {
+                                let path = b + step;
+
+                                match grid[path] {
+                                    b'#' => {
+                                        blocked = true;
+                                        next.clear();
+                                        break;
+                                    }
+                                    side @ b'[' | side @ b']' if !next.contains(&path) => {
+                                        boxes.push(path);
+                                        next.push(path);
+
+                                        if side == b'[' {
+                                            boxes.push(path + Direction::Right.offset());
+                                            next.push(path + Direction::Right.offset());
+                                        } else {
+                                            boxes.push(path + Direction::Left.offset());
+                                            next.push(path + Direction::Left.offset());
+                                        }
+                                    }
+                                    _ => {}
+                                }
+                            }
+
+                            current = next;
+                        }
+                    }
+                    Direction::Left | Direction::Right => {
+                        let mut path = next + step + step;
+
+                        while [b'[', b']'].contains(&grid[path]) {
+                            boxes.push(path);
+                            path += step;
+                        }
+
+                        if grid[path] != b'.' {
+                            blocked = true;
+                        }
+                    }
+                }
+
+                if !blocked {
+                    for &b in boxes.iter().rev() {
+                        grid[b + step] = grid[b];
+                        grid[b] = b'.';
+                    }
+
+                    robot = next;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(coordinates(&grid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(10092));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(9021));
+    }
+}
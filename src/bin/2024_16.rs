@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+
+use advent_of_code::geometry::{Direction, Point};
+use advent_of_code::grid::Grid;
+use advent_of_code::search::{dijkstra, Search};
+
+advent_of_code::solution!(16, parse = parse);
+
+pub struct Maze {
+    grid: Grid<char>,
+    start: Option<Point>,
+    end: Option<Point>,
+}
+
+/// The position of the reindeer and the direction it faces.
+type State = (Point, Direction);
+
+fn successors(grid: &Grid<char>, &(point, d): &State) -> Vec<(State, u32)> {
+    let mut next = vec![
+        ((point, d.turn_left()), 1000),
+        ((point, d.turn_right()), 1000),
+    ];
+
+    let forward = point + d.offset();
+    if grid.get(forward).is_some_and(|&cell| cell != '#') {
+        next.push(((forward, d), 1));
+    }
+
+    next
+}
+
+/// Search all paths from the start, facing east. Returns the search, the lowest score at the end
+/// and the states at the end that reach it.
+fn search(maze: &Maze) -> Option<(Search<State, u32>, u32, Vec<State>)> {
+    let start = maze.start?;
+    let end = maze.end?;
+
+    let search = dijkstra([(start, Direction::Right)], |state| {
+        successors(&maze.grid, state)
+    });
+
+    let ends = Direction::ALL.map(|d| (end, d));
+    let best_cost = ends
+        .iter()
+        .filter_map(|state| search.distance(state))
+        .min()?;
+    let best_ends = ends
+        .into_iter()
+        .filter(|state| search.distance(state) == Some(best_cost))
+        .collect();
+
+    Some((search, best_cost, best_ends))
+}
+
+fn parse(input: &str) -> Maze {
+    let grid = Grid::parse(input, |c| c);
+
+    let start = grid.find(|&c| c == 'S');
+    let end = grid.find(|&c| c == 'E');
+
+    Maze { grid, start, end }
+}
+
+pub fn part_one(maze: &Maze) -> Option<u32> {
+    let (_, best_cost, _) = search(maze)?;
+    Some(best_cost)
+}
+
+pub fn part_two(maze: &Maze) -> Option<u32> {
+    let (search, _, best_ends) = search(maze)?;
+
+    let tiles: HashSet<Point> = search
+        .nodes_on_paths(best_ends)
+        .into_iter()
+        .map(|(point, _)| point)
+        .collect();
+
+    Some(tiles.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let result = part_one(&input);
+        assert_eq!(result, Some(11048));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        let result = part_two(&input);
+        assert_eq!(result, Some(64));
+    }
+}
@@ -0,0 +1,306 @@
+//! Points, vectors and directions on a 2D grid where `y` grows downwards.
+
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A position or offset on a grid, `x` is the column and `y` the row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A [`Point`] used as the difference between two points, e.g. a velocity.
+pub type Vector<T = isize> = Point<T>;
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Point<T> {
+    /// Distance when moving orthogonally, `|dx| + |dy|`.
+    pub fn manhattan(self, other: Self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        let (dx, dy) = self.abs_diff(other);
+        dx + dy
+    }
+
+    /// Distance when also moving diagonally, `max(|dx|, |dy|)`.
+    pub fn chebyshev(self, other: Self) -> T {
+        let (dx, dy) = self.abs_diff(other);
+        dx.max(dy)
+    }
+
+    /// Works for unsigned coordinates as well, since the smaller value is always subtracted.
+    fn abs_diff(self, other: Self) -> (T, T) {
+        let diff = |a: T, b: T| a.max(b) - a.min(b);
+        (diff(self.x, other.x), diff(self.y, other.y))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Scale both coordinates.
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// One of the four orthogonal directions. The variants are ordered clockwise starting upwards,
+/// so `direction as usize` can index per-direction data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Turn 90 degrees counter-clockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Turn 90 degrees clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub const fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// The offset of a single step in this direction.
+    pub const fn offset(self) -> Vector {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+/// Parses arrows (`^>v<`), compass points (`NESW`) and letters (`URDL`), in either case.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase() {
+            '^' | 'N' | 'U' => Ok(Direction::Up),
+            '>' | 'E' | 'R' => Ok(Direction::Right),
+            'V' | 'S' | 'D' => Ok(Direction::Down),
+            '<' | 'W' | 'L' => Ok(Direction::Left),
+            _ => Err(format!("`{value}` is not a direction.")),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(format!("`{s}` is not a direction.")),
+        }
+    }
+}
+
+/// Writes the direction as an arrow, e.g. `^`.
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+        write!(f, "{arrow}")
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(value: Direction) -> Self {
+        value.offset()
+    }
+}
+
+/// One of the four orthogonal and four diagonal directions, ordered clockwise starting upwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turn 45 degrees counter-clockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turn 45 degrees clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The offset of a single step in this direction.
+    pub const fn offset(self) -> Vector {
+        match self {
+            Direction8::Up => Point::new(0, -1),
+            Direction8::UpRight => Point::new(1, -1),
+            Direction8::Right => Point::new(1, 0),
+            Direction8::DownRight => Point::new(1, 1),
+            Direction8::Down => Point::new(0, 1),
+            Direction8::DownLeft => Point::new(-1, 1),
+            Direction8::Left => Point::new(-1, 0),
+            Direction8::UpLeft => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        Self::ALL[value as usize * 2]
+    }
+}
+
+impl From<Direction8> for Vector {
+    fn from(value: Direction8) -> Self {
+        value.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Direction8, Point};
+
+    #[test]
+    fn computes_with_points() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(a - b, Point::new(-3, 4));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point::<u32>::new(5, 1).manhattan(Point::new(2, 3)), 5);
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.reverse(), Direction8::UpLeft);
+        assert_eq!(Direction8::from(Direction::Down), Direction8::Down);
+        assert_eq!(Point::new(2, 2) + Direction::Up.offset(), Point::new(2, 1));
+    }
+
+    #[test]
+    fn parses_directions() {
+        for (s, direction) in [
+            ("^", Direction::Up),
+            ("E", Direction::Right),
+            ("d", Direction::Down),
+        ] {
+            assert_eq!(s.parse(), Ok(direction));
+        }
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert!("x".parse::<Direction>().is_err());
+        assert!("UP".parse::<Direction>().is_err());
+        assert_eq!(Direction::Left.to_string(), "<");
+    }
+}
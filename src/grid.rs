@@ -0,0 +1,346 @@
+//! A rectangular grid of cells, as used by many puzzles that draw a map with one character per cell.
+//!
+//! Cells are stored row by row in a flat vector. Positions are signed, so neighbours and rays that
+//! leave the grid can be computed without casts and are simply not returned.
+
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::geometry::{Direction, Direction8, Point, Vector};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from cells in row order.
+    ///
+    /// # Panics
+    /// If the number of cells is not `width * height`.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "expected {width}x{height} cells, got {}",
+            cells.len()
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a grid with one cell per character, mapping each character with `mapper`.
+    /// Trailing empty lines are ignored.
+    ///
+    /// # Panics
+    /// If the lines have different lengths. The message names the first line that does not match.
+    pub fn parse(input: &str, mut mapper: impl FnMut(char) -> T) -> Self {
+        let lines: Vec<&str> = input.trim_end_matches(['\n', '\r']).lines().collect();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            let len = line.chars().count();
+            assert_eq!(
+                len,
+                width,
+                "line {} has {len} cells, expected {width}: `{line}`",
+                y + 1
+            );
+            cells.extend(line.chars().map(&mut mapper));
+        }
+
+        Self::from_vec(width, lines.len(), cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `point` lies within the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// All points of the grid in row order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Self::point_at(x, y)))
+    }
+
+    /// All cells with their position in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The cells of row `y`.
+    ///
+    /// # Panics
+    /// If `y` is out of bounds.
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(y < self.height, "row {y} out of bounds");
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// The cells of column `x`.
+    ///
+    /// # Panics
+    /// If `x` is out of bounds.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The cells from `start` in steps of `step` until the edge of the grid, including `start`.
+    pub fn ray(&self, start: Point, step: Vector) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |&point| Some(point + step))
+            .map_while(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// The diagonals that run down and to the right, starting with the one in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| Self::point_at(0, y))
+            .chain((1..self.width).map(|x| Self::point_at(x, 0)));
+
+        starts.map(|start| {
+            self.ray(start, Direction8::DownRight.offset())
+                .map(|(_, cell)| cell)
+        })
+    }
+
+    /// The diagonals that run down and to the left, starting with the one in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last = self.width.saturating_sub(1);
+        let starts = (0..self.width)
+            .map(|x| Self::point_at(x, 0))
+            .chain((1..self.height).map(move |y| Self::point_at(last, y)));
+
+        starts.map(|start| {
+            self.ray(start, Direction8::DownLeft.offset())
+                .map(|(_, cell)| cell)
+        })
+    }
+
+    /// The orthogonal neighbours of `point` that lie within the grid.
+    pub fn neighbours_4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, Direction::ALL.map(Direction::offset))
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` that lie within the grid.
+    pub fn neighbours_8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbours(point, Direction8::ALL.map(Direction8::offset))
+    }
+
+    /// The position of the first cell in row order that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// The positions of all cells that match `predicate` in row order.
+    pub fn positions<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// A grid of the same size with every cell mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        point: Point,
+        offsets: [Vector; N],
+    ) -> impl Iterator<Item = (Point, &T)> {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbour = point + offset;
+            self.get(neighbour).map(|cell| (neighbour, cell))
+        })
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn point_at(x: usize, y: usize) -> Point {
+        Point::new(x as isize, y as isize)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self::from_vec(width, height, vec![value; width * height])
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self::from_vec(self.height, self.width, cells)
+    }
+
+    /// Rotate by 90 degrees clockwise, the left column becomes the top row.
+    pub fn rotate_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).rev().cloned())
+            .collect();
+        Self::from_vec(self.height, self.width, cells)
+    }
+
+    /// Rotate by 90 degrees counter-clockwise, the right column becomes the top row.
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+        Self::from_vec(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is out of bounds"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is out of bounds"))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::geometry::Point;
+
+    fn get_mock_grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn parses_and_gets_cells() {
+        let grid = get_mock_grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    #[should_panic(expected = "line 2 has 2 cells")]
+    fn rejects_ragged_lines() {
+        Grid::parse("abc\nde", |c| c);
+    }
+
+    #[test]
+    fn iterates_lines() {
+        let grid = get_mock_grid();
+        let collect = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+
+        assert_eq!(collect(&mut grid.row(1)), "def");
+        assert_eq!(collect(&mut grid.column(1)), "be");
+        assert_eq!(
+            grid.diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            grid.anti_diagonals()
+                .map(|mut d| collect(&mut d))
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    fn finds_neighbours() {
+        let grid = get_mock_grid();
+        let corner: Vec<char> = grid
+            .neighbours_4(Point::new(0, 0))
+            .map(|(_, &c)| c)
+            .collect();
+        assert_eq!(corner, ['b', 'd']);
+        assert_eq!(grid.neighbours_8(Point::new(1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn finds_positions() {
+        let mut grid = get_mock_grid();
+        grid[Point::new(0, 1)] = 'a';
+        assert_eq!(grid.find(|&c| c == 'e'), Some(Point::new(1, 1)));
+        assert_eq!(
+            grid.positions(|&c| c == 'a').collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(0, 1)]
+        );
+    }
+
+    #[test]
+    fn transforms_grid() {
+        let grid = get_mock_grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+    }
+}
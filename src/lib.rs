@@ -1,3 +1,9 @@
+pub mod geometry;
+pub mod grid;
+pub mod ocr;
+pub mod parse;
+pub mod region;
+pub mod search;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
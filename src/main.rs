@@ -1,4 +1,6 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, time, verify,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -6,76 +8,172 @@ use advent_of_code::template::Day;
 #[cfg(feature = "today")]
 use std::process;
 
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        output::Format, readme_benchmarks::TableFormat, stats::BenchConfig, Day, Year,
+    };
+    use std::{process, time::Duration};
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
+        Examples {
+            year: Year,
+            day: Day,
+            overwrite: bool,
+        },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            watch: bool,
+            example: bool,
+            input: Option<String>,
+            timeout: Option<u64>,
+            format: Option<Format>,
         },
         All {
-            release: bool,
+            year: Year,
+            parallel: bool,
+            timeout: Option<Duration>,
+            format: Option<Format>,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            bench: BenchConfig,
+            check: bool,
+            threshold: Option<f64>,
+            timeout: Option<Duration>,
+            format: Option<Format>,
+            table_format: Option<TableFormat>,
+        },
+        TimeHistory {
+            year: Year,
+            day: Day,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
+            accept: bool,
+            timeout: Option<Duration>,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            year: Year,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        let year = parse_year(&mut args)?;
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
-                release: args.contains("--release"),
+                year,
+                parallel: args.contains("--parallel"),
+                timeout: parse_timeout(&mut args)?,
+                format: args.opt_value_from_str("--format")?,
             },
             Some("time") => {
+                if let Some(day) = args.opt_value_from_str("--history")? {
+                    return finish(args, AppArguments::TimeHistory { year, day });
+                }
+
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let check = args.contains("--check");
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let timeout = parse_timeout(&mut args)?;
+                let format = args.opt_value_from_str("--format")?;
+                let table_format = args.opt_value_from_str("--table-format")?;
+
+                let defaults = BenchConfig::default();
+                let bench = BenchConfig {
+                    warmup: args
+                        .opt_value_from_str("--warmup")?
+                        .unwrap_or(defaults.warmup),
+                    time_budget: args
+                        .opt_value_from_str("--budget")?
+                        .map_or(defaults.time_budget, Duration::from_millis),
+                    iterations: args.opt_value_from_str("--iterations")?,
+                    ..defaults
+                };
 
                 AppArguments::Time {
+                    year,
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    bench,
+                    check,
+                    threshold,
+                    timeout,
+                    format,
+                    table_format,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                year,
+                accept: args.contains("--accept"),
+                timeout: parse_timeout(&mut args)?,
+                day: args.opt_free_from_str()?,
+            },
             Some("download") => AppArguments::Download {
+                year,
+                day: args.free_from_str()?,
+            },
+            Some("examples") => AppArguments::Examples {
+                year,
                 day: args.free_from_str()?,
+                overwrite: args.contains("--overwrite"),
             },
             Some("read") => AppArguments::Read {
+                year,
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
+                year,
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
             },
             Some("solve") => AppArguments::Solve {
+                year,
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                watch: args.contains("--watch"),
+                example: args.contains("--example"),
+                input: args.opt_value_from_str("--input")?,
+                timeout: args.opt_value_from_str("--timeout")?,
+                format: args.opt_value_from_str("--format")?,
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today { year },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -86,6 +184,31 @@ mod args {
             }
         };
 
+        finish(args, app_args)
+    }
+
+    /// Uses the year passed with `--year`, falls back to `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified. Pass `--year <YYYY>` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    /// Maximum time in seconds a single part may run, passed with `--timeout`.
+    fn parse_timeout(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+        Ok(args
+            .opt_value_from_str("--timeout")?
+            .map(Duration::from_secs))
+    }
+
+    fn finish(
+        args: pico_args::Arguments,
+        app_args: AppArguments,
+    ) -> Result<AppArguments, Box<dyn std::error::Error>> {
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
@@ -102,33 +225,92 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                parallel,
+                timeout,
+                format,
+            } => all::handle(solutions::SOLUTIONS, year, parallel, timeout, format),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                bench,
+                check,
+                threshold,
+                timeout,
+                format,
+                table_format,
+            } => {
+                time::handle(
+                    solutions::SOLUTIONS,
+                    year,
+                    day,
+                    all,
+                    store,
+                    bench,
+                    check,
+                    threshold,
+                    timeout,
+                    format,
+                    table_format,
+                );
+            }
+            AppArguments::TimeHistory { year, day } => time::handle_history(year, day),
+            AppArguments::Verify {
+                year,
+                day,
+                accept,
+                timeout,
+            } => {
+                verify::handle(solutions::SOLUTIONS, year, day, accept, timeout);
+            }
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Examples {
+                year,
+                day,
+                overwrite,
+            } => examples::handle(year, day, overwrite),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(year, day, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
                 }
             }
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                watch,
+                example,
+                input,
+                timeout,
+                format,
+            } => {
+                if watch {
+                    solve::handle_watch(year, day, release);
+                } else {
+                    solve::handle(
+                        year, day, release, dhat, submit, example, input, timeout, format,
+                    );
+                }
+            }
             #[cfg(feature = "today")]
-            AppArguments::Today => {
+            AppArguments::Today { year } => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        scaffold::handle(year, day, false);
+                        download::handle(year, day);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,184 @@
+//! Recognizes capital letters drawn with `#` and `.`, as produced by some puzzles.
+//!
+//! Supports the two fonts Advent of Code uses: letters that are 6 rows high (about 4 columns wide)
+//! and letters that are 10 rows high (6 columns wide). Letters are separated by at least one empty column.
+
+/// Letters of the font that is 6 rows high.
+const FONT_6: [(char, &[&str]); 18] = [
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Letters of the font that is 10 rows high.
+#[rustfmt::skip]
+const FONT_10: [(char, &[&str]); 15] = [
+    ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Decode letters drawn as text. Any character other than `.` and whitespace counts as a lit pixel,
+/// so pictures drawn with e.g. `█` work as well. Leading and trailing empty lines are ignored.
+///
+/// Returns `None` if the picture has an unsupported height or contains an unknown letter.
+pub fn decode(art: &str) -> Option<String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect())
+        .collect();
+
+    let start = rows.iter().position(|row| row.contains(&true))?;
+    let end = rows.iter().rposition(|row| row.contains(&true))?;
+
+    decode_rows(&rows[start..=end])
+}
+
+/// Decode letters from rows of pixels, where `true` is a lit pixel.
+/// Rows may have different lengths, missing pixels count as dark.
+///
+/// Returns `None` if the picture has an unsupported height or contains an unknown letter.
+pub fn decode_rows<R: AsRef<[bool]>>(rows: &[R]) -> Option<String> {
+    let font: &[(char, &[&str])] = match rows.len() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(|row| row.as_ref().len()).max()?;
+    let pixel = |x: usize, y: usize| rows[y].as_ref().get(x).copied().unwrap_or(false);
+    let is_empty_column = |x: usize| (0..rows.len()).all(|y| !pixel(x, y));
+
+    let mut decoded = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if is_empty_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !is_empty_column(x) {
+            x += 1;
+        }
+
+        let glyph: Vec<String> = (0..rows.len())
+            .map(|y| {
+                (start..x)
+                    .map(|x| if pixel(x, y) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        decoded.push(recognize(font, &glyph)?);
+    }
+
+    (!decoded.is_empty()).then_some(decoded)
+}
+
+fn is_lit(c: char) -> bool {
+    c != '.' && !c.is_whitespace()
+}
+
+/// Look up a glyph whose empty columns on either side were removed.
+fn recognize(font: &[(char, &[&str])], glyph: &[String]) -> Option<char> {
+    font.iter()
+        .find(|(_, letter)| trim_columns(letter) == glyph)
+        .map(|(c, _)| *c)
+}
+
+/// Remove empty columns on either side of a letter, some letters of the fonts are padded.
+fn trim_columns<'a>(rows: &[&'a str]) -> Vec<&'a str> {
+    let is_empty_column = |x: usize| rows.iter().all(|row| row.as_bytes()[x] == b'.');
+
+    let width = rows[0].len();
+    let start = (0..width).find(|&x| !is_empty_column(x)).unwrap_or(0);
+    let end = (0..width)
+        .rfind(|&x| !is_empty_column(x))
+        .map_or(width, |x| x + 1);
+
+    rows.iter().map(|row| &row[start..end]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, decode_rows};
+
+    #[test]
+    fn decodes_small_font() {
+        let art = [
+            "#..#.####.###..###.",
+            "#..#.#....#..#.#..#",
+            "####.###..###..#..#",
+            "#..#.#....#..#.###.",
+            "#..#.#....#..#.#...",
+            "#..#.####.###..#...",
+        ]
+        .join("\n");
+        assert_eq!(decode(&art), Some("HEBP".into()));
+    }
+
+    #[test]
+    fn decodes_large_font_with_other_characters() {
+        let art = [
+            "",
+            "█    █  █████ ",
+            "██   █  █    █",
+            "██   █  █    █",
+            "█ █  █  █    █",
+            "█ █  █  █████ ",
+            "█  █ █  █     ",
+            "█  █ █  █     ",
+            "█   ██  █     ",
+            "█   ██  █     ",
+            "█    █  █     ",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(decode(&art), Some("NP".into()));
+    }
+
+    #[test]
+    fn decodes_rows_of_pixels() {
+        let rows: Vec<Vec<bool>> = ["###", ".#.", ".#.", ".#.", ".#.", "###"]
+            .iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect();
+        assert_eq!(decode_rows(&rows), Some("I".into()));
+    }
+
+    #[test]
+    fn rejects_unknown_pictures() {
+        assert_eq!(decode("#.\n.#"), None);
+        assert_eq!(decode("#\n#\n#\n#\n#\n#"), None);
+        assert_eq!(decode(""), None);
+    }
+}
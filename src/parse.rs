@@ -0,0 +1,339 @@
+//! Helpers for reading puzzle input: numbers in arbitrary text, sections separated by blank lines
+//! and lines that follow a pattern like `p={},{} v={},{}`.
+//!
+//! Errors name the line that failed, so a typo in a hand-written example is easy to find.
+
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting at 1, if the error happened while parsing a line of a larger input.
+    pub line: Option<usize>,
+    /// The text that could not be parsed.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(text: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Attach a line number, keeping one that is already set.
+    #[must_use]
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+        write!(f, "{} in `{}`", self.message, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// All integers in `s`, e.g. `[94, 34]` for `Button A: X+94, Y+34`.
+/// A `-` is read as sign if it directly precedes the digits and does not follow a letter or digit,
+/// so `3-5` is `[3, 5]` and `v=-3` is `[-3]`. Negative numbers need a signed `T`.
+///
+/// # Panics
+/// If a number does not fit into `T`, e.g. a negative number for an unsigned type.
+pub fn ints<T: FromStr>(s: &str) -> impl Iterator<Item = T> + '_ {
+    tokens(s).map(|token| {
+        token
+            .parse()
+            .unwrap_or_else(|_| panic!("`{token}` is out of range"))
+    })
+}
+
+/// Exactly as many integers as `F` has fields, e.g. `let [a, b]: [i64; 2] = extract(line)?`.
+pub fn extract<F: FromFields>(s: &str) -> Result<F, ParseError> {
+    let fields: Vec<&str> = tokens(s).collect();
+    F::from_fields(&fields).map_err(|message| ParseError::new(s, message))
+}
+
+/// Parse every line of `input` with `f`. Errors get the number of the line that failed.
+pub fn lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Sections of `input` that are separated by one or more blank lines. Works with `\r\n` line endings,
+/// and leading or trailing blank lines do not produce empty sections.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(start) = start {
+        sections.push(&input[start..end]);
+    }
+
+    sections.into_iter()
+}
+
+/// A line format with `{}` for each field, e.g. `p={},{} v={},{}`.
+/// Fields are matched lazily up to the following literal text, so two fields need text in between.
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    /// The literal text before, between and after the fields. There is one more than fields.
+    literals: Vec<String>,
+}
+
+impl Pattern {
+    /// # Panics
+    /// If the pattern has no fields or two fields are not separated by literal text.
+    pub fn new(pattern: &str) -> Self {
+        let literals: Vec<String> = pattern.split("{}").map(String::from).collect();
+        assert!(
+            literals.len() > 1,
+            "pattern `{pattern}` has no fields, use `{{}}` for each field"
+        );
+
+        let inner = &literals[1..literals.len() - 1];
+        assert!(
+            inner.iter().all(|literal| !literal.is_empty()),
+            "fields must be separated by text in pattern `{pattern}`"
+        );
+
+        Self { literals }
+    }
+
+    /// The text of each field, in order.
+    pub fn fields<'a>(&self, line: &'a str) -> Result<Vec<&'a str>, ParseError> {
+        let mismatch = |literal: &str| ParseError::new(line, format!("expected `{literal}`"));
+
+        let first = &self.literals[0];
+        let mut rest = line
+            .strip_prefix(first.as_str())
+            .ok_or_else(|| mismatch(first))?;
+        let mut fields = Vec::with_capacity(self.literals.len() - 1);
+
+        for (i, literal) in self.literals.iter().enumerate().skip(1) {
+            let is_last = i == self.literals.len() - 1;
+
+            let (field, remainder) = if is_last {
+                let field = rest
+                    .strip_suffix(literal.as_str())
+                    .ok_or_else(|| mismatch(literal))?;
+                (field, "")
+            } else {
+                rest.split_once(literal.as_str())
+                    .ok_or_else(|| mismatch(literal))?
+            };
+
+            fields.push(field);
+            rest = remainder;
+        }
+
+        Ok(fields)
+    }
+
+    /// Parse the fields of a line, e.g. `let (x, y): (i64, i64) = pattern.parse(line)?`.
+    pub fn parse<F: FromFields>(&self, line: &str) -> Result<F, ParseError> {
+        let fields = self.fields(line)?;
+        F::from_fields(&fields).map_err(|message| ParseError::new(line, message))
+    }
+
+    /// Parse every line of `input`. Errors name the line that failed.
+    pub fn parse_lines<F: FromFields>(&self, input: &str) -> Result<Vec<F>, ParseError> {
+        lines(input, |line| self.parse(line))
+    }
+}
+
+/// Types that are built from a fixed number of text fields, each parsed with [`FromStr`].
+/// Implemented for arrays and for tuples of up to six elements.
+pub trait FromFields: Sized {
+    fn from_fields(fields: &[&str]) -> Result<Self, String>;
+}
+
+fn parse_field<T: FromStr>(field: &str) -> Result<T, String> {
+    field
+        .trim()
+        .parse()
+        .map_err(|_| format!("`{field}` is not a valid {}", std::any::type_name::<T>()))
+}
+
+fn check_arity(fields: &[&str], expected: usize) -> Result<(), String> {
+    if fields.len() == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "expected {expected} values, found {}",
+            fields.len()
+        ))
+    }
+}
+
+impl<T: FromStr, const N: usize> FromFields for [T; N] {
+    fn from_fields(fields: &[&str]) -> Result<Self, String> {
+        check_arity(fields, N)?;
+        let values: Vec<T> = fields
+            .iter()
+            .map(|field| parse_field(field))
+            .collect::<Result<_, _>>()?;
+        // the arity was checked, so the conversion can't fail.
+        values
+            .try_into()
+            .map_err(|_| format!("expected {N} values"))
+    }
+}
+
+macro_rules! impl_from_fields_for_tuple {
+    ($len:expr, $($t:ident $i:tt),+) => {
+        impl<$($t: FromStr),+> FromFields for ($($t,)+) {
+            fn from_fields(fields: &[&str]) -> Result<Self, String> {
+                check_arity(fields, $len)?;
+                Ok(($(parse_field::<$t>(fields[$i])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields_for_tuple!(1, A 0);
+impl_from_fields_for_tuple!(2, A 0, B 1);
+impl_from_fields_for_tuple!(3, A 0, B 1, C 2);
+impl_from_fields_for_tuple!(4, A 0, B 1, C 2, D 3);
+impl_from_fields_for_tuple!(5, A 0, B 1, C 2, D 3, E 4);
+impl_from_fields_for_tuple!(6, A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// The integers of `s` as text, including their sign.
+fn tokens(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        if i == bytes.len() {
+            return None;
+        }
+
+        let mut start = i;
+        let is_sign = start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+        if is_sign {
+            start -= 1;
+        }
+
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        Some(&s[start..i])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract, ints, lines, sections, ParseError, Pattern};
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(
+            ints::<i64>("p=0,4 v=3,-3").collect::<Vec<_>>(),
+            [0, 4, 3, -3]
+        );
+        assert_eq!(ints::<u32>("3-5, x-7").collect::<Vec<_>>(), [3, 5, 7]);
+        assert_eq!(ints::<i32>("3-5, x-7").collect::<Vec<_>>(), [3, 5, 7]);
+        assert_eq!(ints::<i32>("-1 -2").collect::<Vec<_>>(), [-1, -2]);
+        assert_eq!(ints::<u8>("none").count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "`-3` is out of range")]
+    fn rejects_negative_unsigned_integers() {
+        ints::<u32>("v=-3").for_each(drop);
+    }
+
+    #[test]
+    fn extracts_fixed_number_of_integers() {
+        let line = "Button A: X+94, Y+34";
+        assert_eq!(extract::<[u32; 2]>(line), Ok([94, 34]));
+        assert_eq!(extract::<(u8, i64)>(line), Ok((94, 34)));
+        assert_eq!(
+            extract::<[u32; 3]>(line).unwrap_err().to_string(),
+            "expected 3 values, found 2 in `Button A: X+94, Y+34`"
+        );
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "\r\na\r\nb\r\n\r\n\r\nc\n\n";
+        assert_eq!(sections(input).collect::<Vec<_>>(), ["a\r\nb", "c"]);
+        assert_eq!(sections("").count(), 0);
+    }
+
+    #[test]
+    fn parses_patterns() {
+        let pattern = Pattern::new("p={},{} v={},{}");
+        assert_eq!(pattern.parse("p=0,4 v=3,-3"), Ok([0, 4, 3, -3]));
+
+        let pattern = Pattern::new("{} -> {}");
+        assert_eq!(
+            pattern.parse::<(String, u32)>("a -> 2"),
+            Ok(("a".to_string(), 2))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "has no fields")]
+    fn rejects_patterns_without_fields() {
+        Pattern::new("p=");
+    }
+
+    #[test]
+    fn names_failing_line() {
+        let pattern = Pattern::new("p={},{}");
+        let error = pattern.parse_lines::<[i32; 2]>("p=1,2\np=3,x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: `x` is not a valid i32 in `p=3,x`"
+        );
+
+        let error = lines("1\n2\nthree", |line| {
+            line.parse::<u8>()
+                .map_err(|_| ParseError::new(line, "not a number"))
+        })
+        .unwrap_err();
+        assert_eq!(error.line, Some(3));
+
+        let error = pattern.parse::<[i32; 2]>("q=1,2").unwrap_err();
+        assert_eq!(error.to_string(), "expected `p=` in `q=1,2`");
+    }
+}
@@ -0,0 +1,270 @@
+//! Connected regions of a [`Grid`], e.g. garden plots of the same plant.
+//!
+//! Cells belong to the same region if they are orthogonal neighbours and a custom equivalence holds
+//! for them. Every region reports its area, perimeter, number of sides, bounding box and holes.
+
+use crate::{
+    geometry::{Direction, Direction8, Point},
+    grid::Grid,
+};
+
+/// The smallest rectangle that contains all cells of a region, both corners are included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        self.max.x.abs_diff(self.min.x) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.max.y.abs_diff(self.min.y) + 1
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    fn extend(&mut self, point: Point) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    /// Index of the region in [`Regions`], also used in [`Regions::labels`].
+    pub label: usize,
+    /// Cells of the region, starting with the first one in row order.
+    pub points: Vec<Point>,
+    pub area: usize,
+    /// Number of cell edges that border another region or the edge of the grid.
+    pub perimeter: usize,
+    /// Number of straight sides of the outline, including the outlines of holes.
+    pub sides: usize,
+    pub bounds: Bounds,
+    /// Number of areas that are completely enclosed by this region.
+    pub holes: usize,
+}
+
+impl Region {
+    /// The first cell of the region in row order.
+    pub fn start(&self) -> Point {
+        self.points[0]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Label the regions of `grid`. Orthogonal neighbours `a` and `b` are in the same region
+    /// if `same(a, b)` holds, which should be an equivalence relation.
+    pub fn new<T>(grid: &Grid<T>, same: impl Fn(&T, &T) -> bool) -> Self {
+        let mut labels = Grid::new(grid.width(), grid.height(), usize::MAX);
+        let mut regions = Vec::new();
+
+        for start in grid.points() {
+            if labels[start] != usize::MAX {
+                continue;
+            }
+
+            let label = regions.len();
+            labels[start] = label;
+
+            let mut points = vec![start];
+            let mut stack = vec![start];
+
+            while let Some(point) = stack.pop() {
+                for (next, cell) in grid.neighbours_4(point) {
+                    if labels[next] == usize::MAX && same(&grid[point], cell) {
+                        labels[next] = label;
+                        points.push(next);
+                        stack.push(next);
+                    }
+                }
+            }
+
+            regions.push(points);
+        }
+
+        let regions = regions
+            .into_iter()
+            .enumerate()
+            .map(|(label, points)| measure(&labels, label, points))
+            .collect();
+
+        Self { labels, regions }
+    }
+
+    /// The label of the region of every cell.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// The region that contains `point`.
+    pub fn at(&self, point: Point) -> Option<&Region> {
+        self.labels.get(point).map(|&label| &self.regions[label])
+    }
+
+    pub fn get(&self, label: usize) -> Option<&Region> {
+        self.regions.get(label)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+fn measure(labels: &Grid<usize>, label: usize, points: Vec<Point>) -> Region {
+    let inside = |point: Point| labels.get(point) == Some(&label);
+
+    let mut perimeter = 0;
+    let mut sides = 0;
+    let mut bounds = Bounds {
+        min: points[0],
+        max: points[0],
+    };
+
+    for &point in &points {
+        bounds.extend(point);
+
+        for direction in Direction::ALL {
+            if !inside(point + direction.offset()) {
+                perimeter += 1;
+            }
+
+            // every side ends in two corners and every corner ends two sides,
+            // so counting corners counts sides.
+            let side = direction.turn_right();
+            let (a, b) = (
+                inside(point + direction.offset()),
+                inside(point + side.offset()),
+            );
+            let diagonal = inside(point + direction.offset() + side.offset());
+
+            let is_outer_corner = !a && !b;
+            let is_inner_corner = a && b && !diagonal;
+            if is_outer_corner || is_inner_corner {
+                sides += 1;
+            }
+        }
+    }
+
+    Region {
+        label,
+        area: points.len(),
+        holes: count_holes(&inside, bounds),
+        points,
+        perimeter,
+        sides,
+        bounds,
+    }
+}
+
+/// Count the areas of other cells within `bounds` that can't reach the outside without crossing
+/// the region. Diagonal steps are allowed, since the region only connects orthogonally.
+fn count_holes(inside: &impl Fn(Point) -> bool, bounds: Bounds) -> usize {
+    // a border of one cell around the bounds connects everything that is outside.
+    let origin = bounds.min - Point::new(1, 1);
+    let mut seen = Grid::new(bounds.width() + 2, bounds.height() + 2, false);
+
+    let fill = |start: Point, seen: &mut Grid<bool>| {
+        let mut stack = vec![start];
+        seen[start] = true;
+
+        while let Some(local) = stack.pop() {
+            for direction in Direction8::ALL {
+                let next = local + direction.offset();
+                if seen.get(next) == Some(&false) && !inside(next + origin) {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+    };
+
+    fill(Point::new(0, 0), &mut seen);
+
+    let mut holes = 0;
+    for local in seen.points().collect::<Vec<_>>() {
+        if !seen[local] && !inside(local + origin) {
+            holes += 1;
+            fill(local, &mut seen);
+        }
+    }
+
+    holes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, Regions};
+    use crate::{geometry::Point, grid::Grid};
+
+    #[test]
+    fn measures_regions() {
+        let grid = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC", |c| c);
+        let regions = Regions::new(&grid, |a, b| a == b);
+
+        let measured: Vec<(char, usize, usize, usize)> = regions
+            .iter()
+            .map(|r| (grid[r.start()], r.area, r.perimeter, r.sides))
+            .collect();
+
+        assert_eq!(
+            measured,
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ]
+        );
+
+        let c = regions.at(Point::new(3, 3)).unwrap();
+        assert_eq!(
+            c.bounds,
+            Bounds {
+                min: Point::new(2, 1),
+                max: Point::new(3, 3)
+            }
+        );
+    }
+
+    #[test]
+    fn counts_holes() {
+        let grid = Grid::parse("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO", |c| c);
+        let regions = Regions::new(&grid, |a, b| a == b);
+
+        let outer = regions.at(Point::new(0, 0)).unwrap();
+        assert_eq!((outer.area, outer.holes, outer.sides), (21, 4, 20));
+        assert_eq!(regions.len(), 5);
+        assert_eq!(regions.at(Point::new(1, 1)).unwrap().holes, 0);
+    }
+
+    #[test]
+    fn uses_custom_equivalence() {
+        let grid = Grid::parse("1234\n5678", |c| c.to_digit(10).unwrap());
+        let regions = Regions::new(&grid, |a, b| a % 2 == b % 2);
+        assert_eq!(regions.len(), 4);
+
+        let regions = Regions::new(&grid, |a, b| (*a < 5) == (*b < 5));
+        assert_eq!(regions.len(), 2);
+    }
+}
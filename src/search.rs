@@ -0,0 +1,356 @@
+//! Shortest path searches over states that are generated on the fly by a successor function.
+//!
+//! [`bfs`] and [`dijkstra`] explore everything reachable and keep all shortest paths, so the result
+//! can answer how far a state is, how to get there, and which states lie on any optimal path.
+//! [`astar`] stops at the first goal it reaches and only returns one path.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The states reached by a search, with their distance and the predecessors on all shortest paths.
+/// The predecessors form a DAG that leads back to the start states.
+#[derive(Clone, Debug)]
+pub struct Search<S, C> {
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+    /// States in the order they were settled, which is by non-decreasing distance.
+    order: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Search<S, C> {
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// The distance from the closest start state, or `None` if `state` was not reached.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// All reached states with their distance.
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// The states that precede `state` on a shortest path. Empty for start states.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from a start state to `target`, including both.
+    pub fn path(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every state that lies on any shortest path to one of `targets`, including the targets.
+    pub fn nodes_on_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut stack: Vec<S> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect();
+        let mut nodes: HashSet<S> = stack.iter().cloned().collect();
+
+        while let Some(state) = stack.pop() {
+            for previous in self.predecessors(&state) {
+                if nodes.insert(previous.clone()) {
+                    stack.push(previous.clone());
+                }
+            }
+        }
+
+        nodes
+    }
+
+    /// The number of distinct shortest paths from any start state to every reached state.
+    pub fn path_counts(&self) -> HashMap<S, usize> {
+        let mut counts: HashMap<S, usize> = HashMap::with_capacity(self.order.len());
+
+        for state in &self.order {
+            let predecessors = self.predecessors(state);
+            let count = if predecessors.is_empty() {
+                1
+            } else {
+                predecessors
+                    .iter()
+                    .map(|previous| counts.get(previous).copied().unwrap_or(0))
+                    .sum()
+            };
+
+            counts.insert(state.clone(), count);
+        }
+
+        counts
+    }
+
+    /// The number of distinct shortest paths from any start state to `target`.
+    /// Use [`Search::path_counts`] to count the paths to many targets.
+    pub fn count_paths(&self, target: &S) -> usize {
+        self.path_counts().get(target).copied().unwrap_or(0)
+    }
+
+    /// Record that `next` can be reached via `state` at `cost`. Returns whether `next` improved.
+    fn relax(&mut self, state: &S, next: S, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.distances.get(&next).map(|&known| cost.cmp(&known)) {
+            Some(Ordering::Greater) => false,
+            Some(Ordering::Equal) => {
+                self.predecessors
+                    .entry(next)
+                    .or_default()
+                    .push(state.clone());
+                false
+            }
+            Some(Ordering::Less) | None => {
+                self.distances.insert(next.clone(), cost);
+                self.predecessors.insert(next, vec![state.clone()]);
+                true
+            }
+        }
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.distances.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        let cost = search.distances[&state] + 1;
+
+        for next in successors(&state) {
+            if search.relax(&state, next.clone(), cost) {
+                queue.push_back(next);
+            }
+        }
+
+        search.order.push(state);
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm, `successors` returns the next states with the cost of the step.
+/// Step costs must be positive, `C::default()` is the cost of a start state.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if search
+            .distances
+            .insert(start.clone(), C::default())
+            .is_none()
+        {
+            heap.push(Queued::new(C::default(), start));
+        }
+    }
+
+    while let Some(Queued { priority, state }) = heap.pop() {
+        // a shorter path to this state was already processed.
+        if priority > search.distances[&state] {
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let cost = priority + step;
+            if search.relax(&state, next.clone(), cost) {
+                heap.push(Queued::new(cost, next));
+            }
+        }
+
+        search.order.push(state);
+    }
+
+    search
+}
+
+/// A* search from `start` until `is_goal` holds. `heuristic` estimates the remaining cost to a goal.
+/// It must never overestimate, and drop by at most the cost of a step, like the Manhattan distance
+/// on a grid. Returns a shortest path including both ends and its cost.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs: HashMap<S, C> = HashMap::from([(start.clone(), C::default())]);
+    let mut came_from: HashMap<S, S> = HashMap::new();
+    let mut heap = BinaryHeap::from([Queued::new(heuristic(&start), start)]);
+    let mut closed: HashSet<S> = HashSet::new();
+
+    while let Some(Queued { state, .. }) = heap.pop() {
+        if !closed.insert(state.clone()) {
+            continue;
+        }
+
+        let cost = costs[&state];
+
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(previous) = came_from.get(path.last()?) {
+                path.push(previous.clone());
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                came_from.insert(next.clone(), state.clone());
+                heap.push(Queued::new(next_cost + heuristic(&next), next));
+            }
+        }
+    }
+
+    None
+}
+
+/// An entry of a priority queue. Ordered in reverse by priority only, which turns the max-heap
+/// [`BinaryHeap`] into a min-heap and does not require states to be ordered.
+struct Queued<S, C> {
+    priority: C,
+    state: S,
+}
+
+impl<S, C> Queued<S, C> {
+    fn new(priority: C, state: S) -> Self {
+        Self { priority, state }
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::{geometry::Point, grid::Grid};
+
+    /// A diamond `a -> b, c -> d` with a cheap and an expensive side, and a dead end `e`.
+    fn successors(state: &char) -> Vec<(char, u32)> {
+        match state {
+            'a' => vec![('b', 1), ('c', 2), ('e', 10)],
+            'b' => vec![('d', 2)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn finds_distances_and_paths_with_bfs() {
+        let grid = Grid::parse("...\n.#.\n...", |c| c == '#');
+        let search = bfs([Point::new(0, 0)], |&point| {
+            grid.neighbours_4(point)
+                .filter(|(_, &wall)| !wall)
+                .map(|(next, _)| next)
+                .collect::<Vec<_>>()
+        });
+
+        let corner = Point::new(2, 2);
+        assert_eq!(search.distance(&corner), Some(4));
+        assert_eq!(search.distance(&Point::new(1, 1)), None);
+        assert_eq!(search.path(&corner).map(|path| path.len()), Some(5));
+        assert_eq!(search.count_paths(&corner), 2);
+        assert_eq!(search.path_counts()[&corner], 2);
+        assert_eq!(search.path_counts().len(), 8);
+        assert_eq!(search.nodes_on_paths([corner]).len(), 8);
+    }
+
+    #[test]
+    fn keeps_all_shortest_paths_with_dijkstra() {
+        let search = dijkstra(['a'], successors);
+
+        assert_eq!(search.distance(&'d'), Some(3));
+        assert_eq!(search.distance(&'e'), Some(10));
+        assert_eq!(search.predecessors(&'d'), ['b', 'c']);
+        assert_eq!(search.path(&'d'), Some(vec!['a', 'b', 'd']));
+        assert_eq!(search.count_paths(&'d'), 2);
+
+        let mut nodes: Vec<char> = search.nodes_on_paths(['d']).into_iter().collect();
+        nodes.sort_unstable();
+        assert_eq!(nodes, ['a', 'b', 'c', 'd']);
+    }
+
+    #[test]
+    fn finds_goal_with_astar() {
+        let result = astar('a', successors, |_| 0, |&state| state == 'd');
+        assert_eq!(result, Some((vec!['a', 'b', 'd'], 3)));
+        assert_eq!(astar('a', successors, |_| 0, |&state| state == 'x'), None);
+
+        let start = Point::new(0, 0);
+        let goal = Point::new(3, 2);
+        let (path, cost) = astar(
+            start,
+            |&point: &Point| {
+                crate::geometry::Direction::ALL.map(|direction| (point + direction.offset(), 1))
+            },
+            |point| point.manhattan(goal),
+            |&point| point == goal,
+        )
+        .unwrap();
+        assert_eq!((path.len(), cost), (6, 5));
+    }
+}
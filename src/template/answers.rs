@@ -0,0 +1,392 @@
+/// Local ledger of submitted answers, stored in `data/YYYY/answers/DD.json`.
+/// Used to refuse submissions that are known to be wrong before they reach the server.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs, io,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{aoc_client::Verdict, Day, Year};
+
+/// Cooldown assumed after a wrong answer, the server enforces at least one minute.
+const WRONG_ANSWER_COOLDOWN: u64 = 60;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Reason why an answer is not sent to the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(String),
+    KnownWrong(Verdict),
+    AboveTooHigh(String),
+    BelowTooLow(String),
+    Cooldown(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with `{answer}`.")
+            }
+            Refusal::KnownWrong(verdict) => {
+                write!(f, "this answer was already submitted: {verdict}")
+            }
+            Refusal::AboveTooHigh(bound) => {
+                write!(
+                    f,
+                    "`{bound}` was already too high, this answer is not lower."
+                )
+            }
+            Refusal::BelowTooLow(bound) => {
+                write!(
+                    f,
+                    "`{bound}` was already too low, this answer is not higher."
+                )
+            }
+            Refusal::Cooldown(d) => {
+                write!(f, "submitting is on cooldown, wait {}s.", d.as_secs())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Ledger {
+    pub submissions: Vec<Submission>,
+    /// Seconds since the unix epoch until which no answers should be submitted.
+    pub cooldown_until: Option<u64>,
+}
+
+impl Ledger {
+    /// Read the ledger of a day. If the file is not present, returns an empty ledger.
+    pub fn read(year: Year, day: Day) -> Result<Self, String> {
+        let Ok(s) = fs::read_to_string(get_path(year, day)) else {
+            return Ok(Ledger::default());
+        };
+
+        let json = JsonValue::from_str(&s).or(Err("not valid JSON file."))?;
+        Ledger::try_from(&json)
+    }
+
+    pub fn store(&self, year: Year, day: Day) -> Result<(), io::Error> {
+        let json = JsonValue::from(self)
+            .format()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        fs::create_dir_all(format!("./data/{year}/answers"))?;
+        fs::write(get_path(year, day), json)
+    }
+
+    /// The accepted answer of a part, if any.
+    pub fn correct_answer(&self, part: u8) -> Option<&str> {
+        self.submissions
+            .iter()
+            .find(|s| s.part == part && s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Check whether an answer may be submitted at `now` (seconds since the unix epoch).
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        if let Some(correct) = self.correct_answer(part) {
+            return Err(Refusal::AlreadySolved(correct.into()));
+        }
+
+        let previous = self.submissions.iter().filter(|s| s.part == part);
+
+        for submission in previous.clone() {
+            let is_wrong = matches!(
+                submission.verdict,
+                Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+            );
+
+            if is_wrong && submission.answer == answer {
+                return Err(Refusal::KnownWrong(submission.verdict.clone()));
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            for submission in previous {
+                let Ok(bound) = submission.answer.parse::<i128>() else {
+                    continue;
+                };
+
+                match submission.verdict {
+                    Verdict::TooHigh if value >= bound => {
+                        return Err(Refusal::AboveTooHigh(submission.answer.clone()));
+                    }
+                    Verdict::TooLow if value <= bound => {
+                        return Err(Refusal::BelowTooLow(submission.answer.clone()));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        match self.cooldown_until {
+            Some(until) if until > now => Err(Refusal::Cooldown(Duration::from_secs(until - now))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record the verdict of a submission made at `now` and update the cooldown.
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict, now: u64) {
+        self.cooldown_until = match &verdict {
+            Verdict::Wait(Some(d)) => Some(now + d.as_secs()),
+            Verdict::Wait(None) | Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect => {
+                Some(now + WRONG_ANSWER_COOLDOWN)
+            }
+            Verdict::Correct | Verdict::WrongLevel => None,
+        };
+
+        self.submissions.push(Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+            timestamp: now,
+        });
+    }
+}
+
+/// Current time in seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn get_path(year: Year, day: Day) -> String {
+    format!("./data/{year}/answers/{day}.json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn verdict_to_str(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "correct",
+        Verdict::TooHigh => "too_high",
+        Verdict::TooLow => "too_low",
+        Verdict::Incorrect => "incorrect",
+        Verdict::Wait(_) => "wait",
+        Verdict::WrongLevel => "wrong_level",
+    }
+}
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(verdict_to_str(&value.verdict).into()),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        let wait_seconds = match value.verdict {
+            Verdict::Wait(Some(d)) => JsonValue::Number(d.as_secs() as f64),
+            _ => JsonValue::Null,
+        };
+        map.insert("wait_seconds".into(), wait_seconds);
+
+        #[allow(clippy::cast_precision_loss)]
+        let timestamp = value.timestamp as f64;
+        map.insert("timestamp".into(), JsonValue::Number(timestamp));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let wait = json
+            .get("wait_seconds")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| Duration::from_secs(*x as u64));
+
+        let verdict = match json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.verdict to be a string.")?
+            .as_str()
+        {
+            "correct" => Verdict::Correct,
+            "too_high" => Verdict::TooHigh,
+            "too_low" => Verdict::TooLow,
+            "incorrect" => Verdict::Incorrect,
+            "wait" => Verdict::Wait(wait),
+            "wrong_level" => Verdict::WrongLevel,
+            x => return Err(format!("unknown verdict `{x}`.")),
+        };
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Submission {
+            part: *part as u8,
+            answer: answer.clone(),
+            verdict,
+            timestamp: *timestamp as u64,
+        })
+    }
+}
+
+impl From<&Ledger> for JsonValue {
+    fn from(value: &Ledger) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "submissions".into(),
+            JsonValue::Array(value.submissions.iter().map(JsonValue::from).collect()),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        let cooldown_until = match value.cooldown_until {
+            Some(x) => JsonValue::Number(x as f64),
+            None => JsonValue::Null,
+        };
+        map.insert("cooldown_until".into(), cooldown_until);
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Ledger {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let submissions = json
+            .get("submissions")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected answers.submissions to be an array.")?
+            .iter()
+            .map(Submission::try_from)
+            .collect::<Result<_, _>>()?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let cooldown_until = json
+            .get("cooldown_until")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64);
+
+        Ok(Ledger {
+            submissions,
+            cooldown_until,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Ledger, Refusal};
+    use crate::template::aoc_client::Verdict;
+
+    fn get_mock_ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(1, "100", Verdict::TooHigh, 1000);
+        ledger.record(1, "10", Verdict::TooLow, 1100);
+        ledger.record(1, "abc", Verdict::Incorrect, 1200);
+        ledger
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(1, "abc", 2000),
+            Err(Refusal::KnownWrong(Verdict::Incorrect))
+        );
+        assert_eq!(ledger.check(2, "abc", 2000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_out_of_bounds() {
+        let ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(1, "150", 2000),
+            Err(Refusal::AboveTooHigh("100".into()))
+        );
+        assert_eq!(
+            ledger.check(1, "-3", 2000),
+            Err(Refusal::BelowTooLow("10".into()))
+        );
+        assert_eq!(ledger.check(1, "50", 2000), Ok(()));
+    }
+
+    #[test]
+    fn respects_cooldown() {
+        let mut ledger = get_mock_ledger();
+        assert_eq!(
+            ledger.check(1, "50", 1230),
+            Err(Refusal::Cooldown(Duration::from_secs(30)))
+        );
+
+        ledger.record(1, "50", Verdict::Wait(Some(Duration::from_secs(300))), 2000);
+        assert_eq!(
+            ledger.check(1, "50", 2100),
+            Err(Refusal::Cooldown(Duration::from_secs(200)))
+        );
+        assert_eq!(ledger.check(1, "50", 2300), Ok(()));
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let mut ledger = get_mock_ledger();
+        ledger.record(1, "42", Verdict::Correct, 3000);
+        assert_eq!(ledger.correct_answer(1), Some("42"));
+        assert_eq!(
+            ledger.check(1, "43", 4000),
+            Err(Refusal::AlreadySolved("42".into()))
+        );
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut ledger = get_mock_ledger();
+        ledger.record(2, "7", Verdict::Wait(Some(Duration::from_secs(39))), 1300);
+        let json = JsonValue::from(&ledger);
+        assert_eq!(Ledger::try_from(&json).unwrap(), ledger);
+    }
+}
@@ -0,0 +1,466 @@
+/// Built-in client for the Advent of Code website. Replaces the external `aoc` command-line.
+///
+/// The session token is read from `AOC_SESSION` or from `~/.adventofcode.session` (the file used by aoc-cli).
+/// The base URL defaults to `https://adventofcode.com` and can be overridden with `AOC_BASE_URL`.
+/// Requests identify this repository in their User-Agent, set `AOC_USER_AGENT` to send your own contact instead.
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::template::{Day, Year};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static DEFAULT_USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/lawandothman/aoc-2024)"
+);
+
+/// Set this variable to use the `aoc` command-line instead of the built-in client.
+pub static USE_CLI_ENV: &str = "AOC_USE_CLI";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+    UnexpectedResponse(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session token found. Set AOC_SESSION or write it to ~/.adventofcode.session."
+            ),
+            AocClientError::Status(code, body) => {
+                write!(f, "server responded with status {code}: {}", body.trim())
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::UnexpectedResponse(e) => write!(f, "unexpected response: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(code, response) => {
+                AocClientError::Status(code, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(t) => AocClientError::Transport(t.to_string()),
+        }
+    }
+}
+
+/// Returns `true` if the user asked to use the `aoc` command-line instead of the built-in client.
+pub fn use_cli() -> bool {
+    env::var(USE_CLI_ENV).is_ok_and(|x| !x.is_empty() && x != "0" && x != "false")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The server's reaction to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently. Contains the remaining cooldown if the server sent it.
+    Wait(Option<Duration>),
+    /// The part was already solved or is not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Classify the `<article>` text of an answer response.
+    pub fn from_response(text: &str) -> Option<Self> {
+        if text.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if text.contains("You gave an answer too recently") {
+            Some(Verdict::Wait(parse_wait_time(text)))
+        } else if text.contains("answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if text.contains("answer is too low") {
+            Some(Verdict::TooLow)
+        } else if text.contains("That's not the right answer") {
+            Some(Verdict::Incorrect)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "That's not the right answer, your answer is too high."),
+            Verdict::TooLow => write!(f, "That's not the right answer, your answer is too low."),
+            Verdict::Incorrect => write!(f, "That's not the right answer."),
+            Verdict::Wait(Some(d)) => {
+                write!(f, "You gave an answer too recently, wait {}s.", d.as_secs())
+            }
+            Verdict::Wait(None) => write!(f, "You gave an answer too recently."),
+            Verdict::WrongLevel => write!(f, "This part is already solved or not unlocked yet."),
+        }
+    }
+}
+
+/// Parse a cooldown like `You have 1m 5s left to wait` or `You have 39s left to wait`.
+fn parse_wait_time(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn user_agent() -> String {
+    env::var("AOC_USER_AGENT")
+        .ok()
+        .filter(|x| !x.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_USER_AGENT.into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    year: Year,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, year: Year) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(&user_agent())
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+        }
+    }
+
+    /// Configure a client for a year from the environment, see the module docs.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::new(&base_url, &session, year))
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            self.year.into_inner(),
+            day.into_inner()
+        )
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Fetch the personal puzzle input of a day.
+    pub fn input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetch the puzzle description of a day as the HTML of its `<article>` elements.
+    /// Contains the description of part two once part one is solved.
+    pub fn puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day))?;
+        let articles = extract_articles(&html);
+
+        if articles.is_empty() {
+            return Err(AocClientError::UnexpectedResponse(
+                "puzzle page contains no description.".into(),
+            ));
+        }
+
+        Ok(articles.join("\n"))
+    }
+
+    /// Submit an answer for one part of a day.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Verdict, AocClientError> {
+        let response = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        let html = response.into_string()?;
+        let text = html_to_text(&extract_articles(&html).join("\n"));
+
+        Verdict::from_response(&text).ok_or(AocClientError::UnexpectedResponse(text))
+    }
+}
+
+fn read_session() -> Option<String> {
+    if let Some(session) = env::var("AOC_SESSION")
+        .ok()
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty())
+    {
+        return Some(session);
+    }
+
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+    let path = PathBuf::from(home).join(".adventofcode.session");
+    fs::read_to_string(path)
+        .ok()
+        .filter(|x| !x.trim().is_empty())
+}
+
+/// The built-in client stores the puzzle as HTML, unlike aoc-cli which converts it to markdown,
+/// see [`crate::template::aoc_cli::get_puzzle_path`].
+pub fn get_puzzle_html_path(year: Year, day: Day) -> String {
+    format!("data/{year}/puzzles/{day}.html")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns every `<article ...>...</article>` element of a page.
+pub fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(len) = rest[start..].find("</article>") else {
+            break;
+        };
+        let end = start + len + "</article>".len();
+        articles.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+
+    articles
+}
+
+/// Render puzzle HTML as plain text for the terminal.
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+
+        let Some(len) = rest[start..].find('>') else {
+            break;
+        };
+
+        let tag = &rest[start + 1..start + len];
+        let name = tag
+            .trim_start_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+
+        match (tag.starts_with('/'), name) {
+            (false, "h2") => text.push('\n'),
+            (true, "h2" | "p" | "pre" | "ul") => text.push_str("\n\n"),
+            (false, "li") => text.push_str("- "),
+            (true, "li") => text.push('\n'),
+            _ => {}
+        }
+
+        rest = &rest[start + len + 1..];
+    }
+
+    text.push_str(rest);
+
+    decode_entities(&text)
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Decodes the HTML entities used by the puzzle pages.
+pub fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    use super::{html_to_text, parse_wait_time, AocClient, AocClientError, Verdict};
+    use crate::{day, year};
+
+    /// Serves a single request with the given status and body, sending the request head and body back.
+    fn mock_server(status: u16, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(x) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = x.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+                request.push_str(&line);
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            tx.send(request).unwrap();
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (url, rx) = mock_server(200, "1 2\n3 4\n");
+        let client = AocClient::new(&url, "secret", year!(2024));
+
+        assert_eq!(client.input(day!(1)).unwrap(), "1 2\n3 4\n");
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("session=secret"));
+    }
+
+    #[test]
+    fn fetches_puzzle_articles() {
+        let (url, _rx) = mock_server(
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 5 ---</h2><p>a &lt; b</p></article><p>x</p></main>",
+        );
+        let client = AocClient::new(&url, "secret", year!(2024));
+
+        let puzzle = client.puzzle(day!(5)).unwrap();
+        assert_eq!(
+            puzzle,
+            "<article class=\"day-desc\"><h2>--- Day 5 ---</h2><p>a &lt; b</p></article>"
+        );
+        assert_eq!(html_to_text(&puzzle), "--- Day 5 ---\n\na < b");
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, rx) = mock_server(
+            200,
+            "<article><p>That's not the right answer; your answer is too high. Please wait one minute before trying again.</p></article>",
+        );
+        let client = AocClient::new(&url, "secret", year!(2024));
+
+        assert_eq!(client.submit(day!(3), 2, "42").unwrap(), Verdict::TooHigh);
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("POST /2024/day/3/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_status_errors() {
+        let (url, _rx) = mock_server(404, "Not found");
+        let client = AocClient::new(&url, "secret", year!(2024));
+
+        match client.input(day!(25)) {
+            Err(AocClientError::Status(404, body)) => assert_eq!(body, "Not found"),
+            x => panic!("unexpected result: {x:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::from_response("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 5s left to wait."
+            ),
+            Some(Verdict::Wait(Some(Duration::from_secs(65))))
+        );
+        assert_eq!(Verdict::from_response("Something else"), None);
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            parse_wait_time("You have 39s left to wait."),
+            Some(Duration::from_secs(39))
+        );
+        assert_eq!(parse_wait_time("Please wait."), None);
+    }
+}
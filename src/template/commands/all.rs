@@ -1,5 +1,30 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{
+    all_days,
+    output::Format,
+    run_multi::{run_multi, run_multi_parallel},
+    runner::{RunOptions, Solution},
+    Year,
+};
+
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    parallel: bool,
+    timeout: Option<Duration>,
+    format: Option<Format>,
+) {
+    let days = all_days().collect();
+    let options = RunOptions {
+        timeout,
+        format,
+        ..RunOptions::default()
+    };
+
+    if parallel {
+        run_multi_parallel(solutions, year, &days, &options);
+    } else {
+        run_multi(solutions, year, &days, &options);
+    }
 }
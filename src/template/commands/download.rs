@@ -1,14 +1,43 @@
-use crate::template::{aoc_cli, Day};
-use std::process;
+use crate::template::aoc_cli::get_input_path;
+use crate::template::aoc_client::{self, get_puzzle_html_path, AocClient};
+use crate::template::{aoc_cli, Day, Year};
+use std::{fs, process};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(year: Year, day: Day) {
+    if aoc_client::use_cli() {
+        if aoc_cli::check().is_err() {
+            eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+            process::exit(1);
+        }
+
+        if let Err(e) = aoc_cli::download(year, day) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        };
+
+        return;
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    let client = AocClient::from_env(year).unwrap_or_else(|e| {
+        eprintln!("failed to set up client: {e}");
         process::exit(1);
-    };
+    });
+
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_html_path(year, day);
+
+    let result = client
+        .input(day)
+        .and_then(|input| Ok(fs::write(&input_path, input)?))
+        .and_then(|()| client.puzzle(day))
+        .and_then(|puzzle| Ok(fs::write(&puzzle_path, puzzle)?));
+
+    if let Err(e) = result {
+        eprintln!("failed to download day {day}: {e}");
+        process::exit(1);
+    }
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
 }
@@ -0,0 +1,90 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{
+    aoc_cli::get_puzzle_path,
+    aoc_client::get_puzzle_html_path,
+    puzzle::{extract_examples, patch_assertion, replace_in_test},
+    Day, Year,
+};
+
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    // the built-in client stores HTML, aoc-cli stores markdown.
+    let puzzle_path = [get_puzzle_html_path(year, day), get_puzzle_path(year, day)]
+        .into_iter()
+        .find(|path| Path::new(path).exists())
+        .unwrap_or_else(|| get_puzzle_html_path(year, day));
+    let example_path = format!("data/{year}/examples/{day}.txt");
+    let part_two_example_path = format!("data/{year}/examples/{day}-2.txt");
+    let module_path = format!("src/bin/{year}_{day}.rs");
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read \"{puzzle_path}\". Run `cargo download {day} --year {year}` first."
+        );
+        process::exit(1);
+    };
+
+    let [part_one, part_two] = extract_examples(&puzzle);
+
+    let Some(example) = part_one.input else {
+        eprintln!("Found no example in \"{puzzle_path}\".");
+        process::exit(1);
+    };
+
+    let has_example = fs::read_to_string(&example_path).is_ok_and(|x| !x.trim().is_empty());
+    if has_example && !overwrite {
+        eprintln!("\"{example_path}\" is not empty. Pass `--overwrite` to replace it.");
+        process::exit(1);
+    }
+
+    write_file(&example_path, &example);
+
+    let mut module = fs::read_to_string(&module_path).ok();
+
+    if let Some(part_two_example) = part_two.input.filter(|x| *x != example) {
+        write_file(&part_two_example_path, &part_two_example);
+        module = module.map(|source| {
+            replace_in_test(
+                &source,
+                "test_part_two",
+                "read_file(\"examples\", YEAR, DAY)",
+                "read_file_part(\"examples\", YEAR, DAY, 2)",
+            )
+            .unwrap_or(source)
+        });
+    }
+
+    if let Some(mut source) = module {
+        for (test_name, answer) in [
+            ("test_part_one", part_one.answer),
+            ("test_part_two", part_two.answer),
+        ] {
+            let Some(answer) = answer else {
+                continue;
+            };
+
+            match patch_assertion(&source, test_name, &answer) {
+                Some(patched) => {
+                    println!("Expecting `{answer}` in `{test_name}`");
+                    source = patched;
+                }
+                None => println!("Left `{test_name}` unchanged, it was already edited."),
+            }
+        }
+
+        write_file(&module_path, &source);
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo test --bin {year}_{day}` to run the example tests.");
+}
+
+fn write_file(path: &str, contents: &str) {
+    match fs::write(path, contents) {
+        Ok(()) => println!("Wrote \"{path}\""),
+        Err(e) => {
+            eprintln!("Failed to write \"{path}\": {e}");
+            process::exit(1);
+        }
+    }
+}
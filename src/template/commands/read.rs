@@ -1,15 +1,37 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{aoc_cli, Day};
+use crate::template::aoc_client::{self, get_puzzle_html_path, html_to_text, AocClient};
+use crate::template::{aoc_cli, Day, Year};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+pub fn handle(year: Year, day: Day) {
+    if aoc_client::use_cli() {
+        if aoc_cli::check().is_err() {
+            eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+            process::exit(1);
+        }
+
+        if let Err(e) = aoc_cli::read(year, day) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        };
+
+        return;
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    let puzzle_path = get_puzzle_html_path(year, day);
+
+    let result = AocClient::from_env(year)
+        .and_then(|client| client.puzzle(day))
+        .and_then(|puzzle| {
+            fs::write(&puzzle_path, &puzzle)?;
+            Ok(puzzle)
+        });
+
+    match result {
+        Ok(puzzle) => println!("{}", html_to_text(&puzzle)),
+        Err(e) => {
+            eprintln!("failed to read day {day}: {e}");
+            process::exit(1);
+        }
+    }
 }
@@ -1,9 +1,40 @@
-use std::process::{Command, Stdio};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    thread,
+    time::Duration,
+};
 
-use crate::template::Day;
+use crate::template::{
+    output::Format,
+    results::{self, Status, PARSE_STEP, RESULTS_FILE_ENV},
+    watch::{parse_test_output, Target, Watcher},
+    Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Number of lines of compiler or runtime errors shown in watch mode.
+const MAX_ERROR_LINES: usize = 30;
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    example: bool,
+    input: Option<String>,
+    timeout: Option<u64>,
+    format: Option<Format>,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        format!("{year}_{day}"),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +54,23 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.to_string());
+    }
+
+    if let Some(format) = format {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    } else if example {
+        cmd_args.push("--example".to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -32,3 +80,137 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Rerun the tests and both parts of a day whenever its source, the library or its examples change.
+pub fn handle_watch(year: Year, day: Day, release: bool) {
+    let bin = format!("{year}_{day}");
+
+    // the library is everything in `src` except the solutions of other days.
+    let mut watcher = Watcher::new(vec![
+        Target::File(PathBuf::from(format!("src/bin/{bin}.rs"))),
+        Target::Tree {
+            dir: PathBuf::from("src"),
+            exclude: vec![PathBuf::from("src/bin")],
+        },
+        Target::Prefixed {
+            dir: PathBuf::from(format!("data/{year}/examples")),
+            prefix: day.to_string(),
+        },
+    ]);
+
+    let results_path = env::temp_dir().join(format!("aoc-watch-{bin}.jsonl"));
+
+    loop {
+        // clear the screen and move the cursor to the top left.
+        print!("\x1b[2J\x1b[H");
+        println!("{ANSI_BOLD}Day {day} ({year}){ANSI_RESET} {ANSI_ITALIC}watching for changes, press Ctrl-C to stop.{ANSI_RESET}");
+        println!();
+
+        if run_tests(&bin, release) {
+            run_parts(&bin, release, &results_path);
+        }
+
+        while !watcher.poll() {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// Run the example tests and print a summary. Returns `false` if the build failed.
+fn run_tests(bin: &str, release: bool) -> bool {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["test", "--bin", bin]);
+    if release {
+        cmd.arg("--release");
+    }
+
+    let output = match cmd.output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let Some(summary) = parse_test_output(&stdout) else {
+        println!("{ANSI_BOLD}Build failed{ANSI_RESET}");
+        println!();
+        print_errors(&output.stderr);
+        return false;
+    };
+
+    if summary.failed.is_empty() {
+        println!("Tests   ✔ {} passed", summary.passed);
+    } else {
+        println!(
+            "Tests   ✔ {} passed · ✖ {} failed: {}",
+            summary.passed,
+            summary.failed.len(),
+            summary.failed.join(", ")
+        );
+    }
+
+    true
+}
+
+/// Run the solution against the input and print one line per part.
+fn run_parts(bin: &str, release: bool, results_path: &Path) {
+    let _ = fs::remove_file(results_path);
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--quiet", "--bin", bin]);
+    if release {
+        cmd.arg("--release");
+    }
+
+    let output = cmd.env(RESULTS_FILE_ENV, results_path).output();
+
+    let results = fs::read_to_string(results_path)
+        .map_err(|e| e.to_string())
+        .and_then(|s| results::parse_lines(&s))
+        .unwrap_or_default();
+
+    for result in &results {
+        let label = match result.part {
+            PARSE_STEP => "Parse ".to_string(),
+            part => format!("Part {part}"),
+        };
+
+        match (&result.answer, &result.status) {
+            (Some(answer), Status::Decoded(art)) => {
+                println!(
+                    "{label}  {answer} (decoded from art, {:.1?})\n{art}",
+                    result.duration
+                )
+            }
+            (Some(answer), Status::Art) => {
+                println!("{label}  ▼ art ({:.1?})\n{answer}", result.duration)
+            }
+            (Some(answer), Status::Inspect) => println!(
+                "{label}  {answer} (needs manual inspection, {:.1?})",
+                result.duration
+            ),
+            (Some(answer), _) => println!("{label}  {answer} ({:.1?})", result.duration),
+            (None, Status::Unsolved) => println!("{label}  ✖"),
+            (None, Status::Solved) => println!("{label}  ({:.1?})", result.duration),
+            (None, status) => println!("{label}  ✖ {status}"),
+        }
+    }
+
+    match output {
+        Ok(output) if !output.status.success() => {
+            println!();
+            print_errors(&output.stderr);
+        }
+        Err(e) => eprintln!("Failed to run cargo: {e}"),
+        _ => {}
+    }
+}
+
+fn print_errors(stderr: &[u8]) {
+    String::from_utf8_lossy(stderr)
+        .lines()
+        .take(MAX_ERROR_LINES)
+        .for_each(|line| println!("{line}"));
+}
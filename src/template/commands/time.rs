@@ -1,15 +1,34 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process, time::Duration};
 
+use crate::template::output::Format;
+use crate::template::readme_benchmarks::TableFormat;
 use crate::template::run_multi::run_multi;
+use crate::template::runner::{RunOptions, Solution};
+use crate::template::stats::BenchConfig;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, history, readme_benchmarks, regression, Day, Year};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    bench: BenchConfig,
+    check: bool,
+    threshold: Option<f64>,
+    timeout: Option<Duration>,
+    format: Option<Format>,
+    table_format: Option<TableFormat>,
+) {
+    let threshold = threshold.unwrap_or(regression::DEFAULT_THRESHOLD);
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
+            // a regression check compares every day, even if it is already fully benched.
+            if run_all || check {
                 all_days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
@@ -21,20 +40,76 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let Some(timings) = run_multi(
+        solutions,
+        year,
+        &days_to_run,
+        &RunOptions {
+            is_timed: true,
+            bench,
+            timeout,
+            format,
+            ..RunOptions::default()
+        },
+    ) else {
+        eprintln!("A part timed out, so the benchmarks are neither compared nor stored.");
+        if check {
+            process::exit(1);
+        }
+        return;
+    };
+
+    let deltas = regression::compare(&stored_timings, &timings);
+    // formatted output is meant to be parsed, so only the results are printed.
+    let has_regression = match format {
+        Some(_) => deltas.iter().any(|delta| delta.is_regression(threshold)),
+        None => regression::print_report(&deltas, threshold),
+    };
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
+
+        if let Err(e) = history::append(year, &timings) {
+            eprintln!("Failed to append to benchmark history: {e}");
+        }
+
+        if format.is_none() {
+            println!();
+        }
 
-        println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
+        // the README only shows the benchmarks of the default year.
+        if Year::from_env() != Some(year) {
+            if format.is_none() {
                 println!("Stored updated benchmarks.");
             }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
+        } else {
+            match readme_benchmarks::update(year, merged_timings, table_format) {
+                Ok(()) => {
+                    if format.is_none() {
+                        println!("Stored updated benchmarks.");
+                    }
+                }
+                Err(_) => {
+                    eprintln!("Failed to store updated benchmarks.");
+                }
             }
         }
     }
+
+    if check && has_regression {
+        eprintln!("Performance regressed by more than {threshold}%.");
+        process::exit(1);
+    }
+}
+
+/// Show how the timings of a day evolved over all stored runs.
+pub fn handle_history(year: Year, day: Day) {
+    match history::read_day(year, day) {
+        Ok(entries) => history::print_day(day, &entries),
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,49 @@
+use std::{process, time::Duration};
+
+use crate::template::{all_days, runner::Solution, verify, Day, Year};
+
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    day: Option<Day>,
+    accept: bool,
+    timeout: Option<Duration>,
+) {
+    let days: Vec<Day> = match day {
+        Some(day) => vec![day],
+        None => all_days().collect(),
+    };
+
+    if accept {
+        handle_accept(solutions, year, &days, timeout);
+        return;
+    }
+
+    let outcomes = verify::verify_days(solutions, year, &days, timeout);
+
+    if outcomes.is_empty() {
+        println!("No solutions to verify.");
+        return;
+    }
+
+    if !verify::print_report(&outcomes) {
+        process::exit(1);
+    }
+}
+
+/// Record the current answers as known answers, so that later runs are checked against them.
+fn handle_accept(solutions: &[Solution], year: Year, days: &[Day], timeout: Option<Duration>) {
+    match verify::accept_days(solutions, year, days, timeout) {
+        Ok(accepted) if accepted.is_empty() => println!("No answers to accept."),
+        Ok(accepted) => {
+            for (day, part, answer) in accepted {
+                println!("Day {day} Part {part}: accepted `{answer}`");
+            }
+            println!("Stored known answers in data/{year}/answers.json.");
+        }
+        Err(e) => {
+            eprintln!("Failed to store known answers: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,309 @@
+/// Append-only history of benchmark runs, stored next to the `timings.json` snapshot.
+/// Each line of the history file is a JSON object describing the timing of one day at one point in time.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    output::aligned_table,
+    timings::{Timing, Timings},
+    Day, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+/// Timing of a day together with the context it was measured in.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the git commit, if the project is a git repository.
+    pub commit: Option<String>,
+    pub profile: String,
+    pub machine: String,
+    pub timing: Timing,
+}
+
+/// Append the given timings to the history file, tagged with the current context.
+pub fn append(year: Year, timings: &Timings) -> Result<(), io::Error> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = git_commit();
+    let profile = profile();
+    let machine = machine();
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path(year))?;
+
+    for timing in &timings.data {
+        let entry = HistoryEntry {
+            timestamp,
+            commit: commit.clone(),
+            profile: profile.clone(),
+            machine: machine.clone(),
+            timing: timing.clone(),
+        };
+
+        let line = JsonValue::from(&entry)
+            .stringify()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        writeln!(file, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Read all history entries of a day, oldest first. If the file is not present, returns no entries.
+pub fn read_day(year: Year, day: Day) -> Result<Vec<HistoryEntry>, String> {
+    let Ok(s) = fs::read_to_string(get_path(year)) else {
+        return Ok(vec![]);
+    };
+
+    let entries = parse_lines(&s)?;
+    Ok(entries
+        .into_iter()
+        .filter(|e| e.timing.day == day)
+        .collect())
+}
+
+fn parse_lines(s: &str) -> Result<Vec<HistoryEntry>, String> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err(format!("not valid JSON: {line}")))?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+/// Print how the parts of a day evolved over time.
+pub fn print_day(day: Day, entries: &[HistoryEntry]) {
+    println!("{ANSI_BOLD}Day {day} history{ANSI_RESET}");
+    println!("------");
+
+    if entries.is_empty() {
+        println!("No history recorded. Run `cargo time {day} --store` to record one.");
+        return;
+    }
+
+    let rows: Vec<Vec<String>> = entries
+        .iter()
+        .map(|e| {
+            vec![
+                format_timestamp(e.timestamp),
+                e.commit.clone().unwrap_or_else(|| "-".into()),
+                e.profile.clone(),
+                e.machine.clone(),
+                e.timing.parse.clone().unwrap_or_else(|| "-".into()),
+                e.timing.part_1.clone().unwrap_or_else(|| "-".into()),
+                e.timing.part_2.clone().unwrap_or_else(|| "-".into()),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "Date", "Commit", "Profile", "Machine", "Parse", "Part 1", "Part 2",
+    ];
+
+    let lines = aligned_table(&header, &rows);
+    println!("{ANSI_BOLD}{}{ANSI_RESET}", lines[0]);
+    for line in &lines[1..] {
+        println!("{line}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn get_path(year: Year) -> String {
+    format!("./data/{year}/timings_history.jsonl")
+}
+
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
+
+fn profile() -> String {
+    if cfg!(debug_assertions) {
+        "debug".into()
+    } else {
+        "release".into()
+    }
+}
+
+/// Uses `AOC_MACHINE` if set, the host name otherwise.
+fn machine() -> String {
+    env::var("AOC_MACHINE")
+        .ok()
+        .or_else(|| env::var("HOSTNAME").ok())
+        .or_else(|| env::var("COMPUTERNAME").ok())
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/// Formats a unix timestamp as `YYYY-MM-DD HH:MM` in UTC.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let timestamp = value.timestamp as f64;
+        map.insert("timestamp".into(), JsonValue::Number(timestamp));
+
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert("profile".into(), JsonValue::String(value.profile.clone()));
+        map.insert("machine".into(), JsonValue::String(value.machine.clone()));
+        map.insert("timing".into(), JsonValue::from(&value.timing));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.profile to be a string.")?;
+
+        let machine = json
+            .get("machine")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.machine to be a string.")?;
+
+        let timing = json
+            .get("timing")
+            .ok_or("Expected entry to have key `timing`.")
+            .map(Timing::try_from)??;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(HistoryEntry {
+            timestamp: *timestamp as u64,
+            commit: commit.cloned(),
+            profile: profile.clone(),
+            machine: machine.clone(),
+            timing,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{format_timestamp, parse_lines, HistoryEntry};
+    use crate::{day, template::timings::Timing};
+
+    fn get_mock_entry() -> HistoryEntry {
+        HistoryEntry {
+            timestamp: 1_734_172_800,
+            commit: Some("a1b2c3d".into()),
+            profile: "release".into(),
+            machine: "ferris".into(),
+            timing: Timing {
+                parse: Some("2.1µs".into()),
+                part_1: Some("26.7µs".into()),
+                total_nanos: 28_800_f64,
+                ..Timing::new(day!(12))
+            },
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entry = get_mock_entry();
+        let line = JsonValue::from(&entry).stringify().unwrap();
+        let parsed = parse_lines(&format!("{line}\n\n{line}\n")).unwrap();
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].timestamp, entry.timestamp);
+        assert_eq!(parsed[0].commit, entry.commit);
+        assert_eq!(parsed[0].machine, "ferris");
+        assert_eq!(parsed[0].timing.day, day!(12));
+        assert_eq!(parsed[0].timing.part_1, Some("26.7µs".into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_entries_without_timing() {
+        parse_lines(r#"{ "timestamp": 0, "commit": null, "profile": "release", "machine": "x" }"#)
+            .unwrap();
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_734_172_800), "2024-12-14 10:40");
+        assert_eq!(format_timestamp(951_825_600), "2000-02-29 12:00");
+    }
+}
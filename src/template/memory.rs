@@ -0,0 +1,192 @@
+/// Lightweight heap usage tracking for solution parts.
+/// Enabled with the `count-alloc` feature, which installs [`CountingAlloc`] as the global allocator
+/// of every binary that links the library. It is not installed together with `dhat-heap`.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::HashMap,
+    fmt::Display,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use tinyjson::JsonValue;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(all(feature = "count-alloc", not(feature = "dhat-heap")))]
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// A global allocator that wraps the system allocator and counts bytes and allocations.
+///
+/// The counters are shared by all threads, so measurements of parts that run concurrently
+/// (e.g. with `cargo all --parallel`) include the allocations of the other parts.
+pub struct CountingAlloc;
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_alloc(size: usize) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+/// Heap usage of a single run of a solution part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Peak number of bytes allocated on top of what was allocated before the part started.
+    pub peak_bytes: u64,
+    /// Number of allocations, reallocations count as one.
+    pub allocations: u64,
+}
+
+impl Display for MemoryUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} peak · {} allocs",
+            format_bytes(self.peak_bytes),
+            self.allocations
+        )
+    }
+}
+
+/// Run `func` and measure its heap usage. Returns `None` if [`CountingAlloc`] is not installed.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<MemoryUsage>) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+
+    let result = func();
+
+    if !INSTALLED.load(Ordering::Relaxed) {
+        return (result, None);
+    }
+
+    let usage = MemoryUsage {
+        peak_bytes: (PEAK.load(Ordering::Relaxed).saturating_sub(baseline)) as u64,
+        allocations: (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as u64,
+    };
+
+    (result, Some(usage))
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&MemoryUsage> for JsonValue {
+    fn from(value: &MemoryUsage) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let peak_bytes = value.peak_bytes as f64;
+        map.insert("peak_bytes".into(), JsonValue::Number(peak_bytes));
+
+        #[allow(clippy::cast_precision_loss)]
+        let allocations = value.allocations as f64;
+        map.insert("allocations".into(), JsonValue::Number(allocations));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for MemoryUsage {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected memory to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected memory.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(MemoryUsage {
+            peak_bytes: number("peak_bytes")? as u64,
+            allocations: number("allocations")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{format_bytes, MemoryUsage};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let usage = MemoryUsage {
+            peak_bytes: 4096,
+            allocations: 12,
+        };
+        let json = JsonValue::from(&usage);
+        assert_eq!(MemoryUsage::try_from(&json).unwrap(), usage);
+    }
+}
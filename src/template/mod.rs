@@ -1,44 +1,107 @@
-use std::{env, fs};
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, IsTerminal},
+    sync::OnceLock,
+};
 
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod memory;
+pub mod output;
+pub mod readme_benchmarks;
+pub mod results;
 pub mod runner;
+pub mod stats;
 
 pub use day::*;
+pub use year::*;
 
+mod answers;
 mod day;
-mod readme_benchmarks;
+mod history;
+mod puzzle;
+mod regression;
 mod run_multi;
 mod timings;
+mod verify;
+mod watch;
+mod year;
 
-pub const ANSI_ITALIC: &str = "\x1b[3m";
-pub const ANSI_BOLD: &str = "\x1b[1m";
-pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_ITALIC: Ansi = Ansi("\x1b[3m");
+pub const ANSI_BOLD: Ansi = Ansi("\x1b[1m");
+pub const ANSI_RESET: Ansi = Ansi("\x1b[0m");
+
+/// An ANSI escape code that is only written if colors are enabled, see [`colors_enabled`].
+#[derive(Clone, Copy, Debug)]
+pub struct Ansi(&'static str);
+
+impl Display for Ansi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if colors_enabled() {
+            f.write_str(self.0)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Whether stdout is a terminal. Progress that is overwritten later is only printed to terminals.
+pub fn is_interactive() -> bool {
+    static INTERACTIVE: OnceLock<bool> = OnceLock::new();
+    *INTERACTIVE.get_or_init(|| io::stdout().is_terminal())
+}
+
+/// Colors are disabled if stdout is not a terminal or `NO_COLOR` is set, see <https://no-color.org>.
+pub fn colors_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| {
+        is_interactive() && !matches!(env::var("NO_COLOR"), Ok(value) if !value.is_empty())
+    })
+}
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
+    try_read_file(folder, year, day).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if it can't be read.
+pub fn try_read_file(folder: &str, year: Year, day: Day) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let filepath = cwd
+        .join("data")
+        .join(year.to_string())
+        .join(folder)
+        .join(format!("{day}.txt"));
+    fs::read_to_string(filepath)
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(year.to_string())
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The year is read from the name of the solution file, e.g. `src/bin/2024_01.rs`.
+///
+/// Solutions can declare a shared parse step with `solution!(DAY, parse = parse_fn)`.
+/// `parse_fn` receives the raw input and both parts receive a reference to its output.
+/// The parse step is then timed separately from the parts.
+/// A part with its own example (`DD-N.txt`) parses it in a separate step, see [`runner::run_part_parse`].
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -50,19 +113,73 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, parse = $parse:expr) => {
+        $crate::solution!(@impl $day, parse $parse, [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, parse = $parse:expr, 1) => {
+        $crate::solution!(@impl $day, parse $parse, [part_one, 1]);
+    };
+    ($day:expr, parse = $parse:expr, 2) => {
+        $crate::solution!(@impl $day, parse $parse, [part_two, 2]);
+    };
 
     (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@register $day, |input, options| {
+            use $crate::template::runner::*;
+            vec![$({
+                let part_input = options.input.read_part(YEAR, DAY, $part);
+                let input = part_input.as_deref().unwrap_or(input);
+                run_part($func, input, YEAR, DAY, $part, options)
+            }, )*]
+        });
+    };
+
+    (@impl $day:expr, parse $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@register $day, |input, options| {
+            use $crate::template::runner::*;
+            let (parsed, parse_result) = run_parse($parse, input, DAY, options);
+            let mut results = vec![parse_result];
+            $(
+                let result = match options.input.read_part(YEAR, DAY, $part) {
+                    Some(part_input) => match run_part_parse($parse, part_input.as_str(), DAY, $part, options) {
+                        Ok(parsed) => run_part($func, &parsed, YEAR, DAY, $part, options),
+                        Err(result) => *result,
+                    },
+                    None => run_part($func, &parsed, YEAR, DAY, $part, options),
+                };
+                results.push(result);
+            )*
+            results
+        });
+    };
+
+    (@register $day:expr, $run:expr) => {
+        /// The current year.
+        const YEAR: $crate::template::Year = $crate::template::Year::__from_source_path(file!());
+
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
+        /// Entry point used by the main binary to run this day in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::runner::Solution = $crate::template::runner::Solution {
+            year: YEAR,
+            day: DAY,
+            run: $run,
+        };
+
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = RunOptions::from_args();
+            let input = options.input.read(YEAR, DAY).expect("could not open input file");
+            let results = run_solution(&SOLUTION, &input, &options);
+            if let Some(format) = options.format {
+                print!("{}", format.render(&results));
+            }
         }
     };
 }
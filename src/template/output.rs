@@ -0,0 +1,336 @@
+/// Renders the results of solution parts in machine-readable formats, selected with `--format`.
+use std::{fmt::Display, str::FromStr};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    memory::MemoryUsage,
+    results::{PartResult, PARSE_STEP},
+};
+
+/// Output format of `cargo solve`, `cargo all` and `cargo time`.
+/// If no format is passed, results are printed as they are computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+    Markdown,
+    /// An aligned table without colors.
+    Plain,
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+            Format::Markdown => write!(f, "markdown"),
+            Format::Plain => write!(f, "plain"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" | "md" => Ok(Format::Markdown),
+            "plain" => Ok(Format::Plain),
+            s => Err(format!(
+                "unknown format `{s}`, expected one of json, csv, markdown or plain."
+            )),
+        }
+    }
+}
+
+impl Format {
+    /// Render results, one entry per part. The output ends with a newline.
+    pub fn render(self, results: &[PartResult]) -> String {
+        match self {
+            Format::Json => render_json(results),
+            Format::Csv => render_csv(results),
+            Format::Markdown => render_markdown(results),
+            Format::Plain => render_plain(results),
+        }
+    }
+}
+
+const COLUMNS: [&str; 8] = [
+    "day",
+    "part",
+    "answer",
+    "status",
+    "duration_nanos",
+    "samples",
+    "peak_bytes",
+    "allocations",
+];
+
+fn step_name(part: u8) -> String {
+    match part {
+        PARSE_STEP => "parse".into(),
+        part => part.to_string(),
+    }
+}
+
+/// The values of a result in the order of [`COLUMNS`].
+fn cells(result: &PartResult) -> [String; 8] {
+    let memory = |f: fn(&MemoryUsage) -> u64| {
+        result
+            .memory
+            .as_ref()
+            .map_or_else(String::new, |m| f(m).to_string())
+    };
+
+    [
+        result.day.to_string(),
+        step_name(result.part),
+        result.answer.clone().unwrap_or_default(),
+        result.status.to_string(),
+        result.duration.as_nanos().to_string(),
+        result.samples.to_string(),
+        memory(|m| m.peak_bytes),
+        memory(|m| m.allocations),
+    ]
+}
+
+fn render_json(results: &[PartResult]) -> String {
+    let json = JsonValue::Array(results.iter().map(JsonValue::from).collect());
+    let mut out = json.format().unwrap_or_else(|_| "[]".into());
+    out.push('\n');
+    out
+}
+
+fn render_csv(results: &[PartResult]) -> String {
+    let escape = |cell: &str| {
+        if cell.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.to_string()
+        }
+    };
+
+    let mut out = COLUMNS.join(",");
+    out.push('\n');
+
+    for result in results {
+        let row: Vec<String> = cells(result).iter().map(|cell| escape(cell)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+fn render_markdown(results: &[PartResult]) -> String {
+    let mut lines = vec![
+        "| Day | Part | Answer | Status | Time |".to_string(),
+        "| :---: | :---: | :---: | :---: | :---: |".to_string(),
+    ];
+
+    for result in results {
+        let answer = result.answer.as_ref().map_or_else(
+            || "-".into(),
+            |answer| format!("`{}`", answer.replace('|', "\\|").replace('\n', "<br>")),
+        );
+
+        lines.push(format!(
+            "| {} | {} | {} | {} | {:.1?} |",
+            result.day,
+            step_name(result.part),
+            answer,
+            result
+                .status
+                .to_string()
+                .replace('|', "\\|")
+                .replace('\n', "<br>"),
+            result.duration
+        ));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn render_plain(results: &[PartResult]) -> String {
+    let header = ["Day", "Part", "Answer", "Status", "Time"];
+
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|result| {
+            vec![
+                result.day.to_string(),
+                step_name(result.part),
+                result
+                    .answer
+                    .as_ref()
+                    .map_or_else(|| "-".into(), |answer| answer.replace('\n', "\\n")),
+                result.status.to_string().replace('\n', "\\n"),
+                format!("{:.1?}", result.duration),
+            ]
+        })
+        .collect();
+
+    aligned_table(&header, &rows).join("\n") + "\n"
+}
+
+/// Lines of a table whose columns are padded to their widest cell, starting with the header.
+pub fn aligned_table(header: &[&str], rows: &[Vec<String>]) -> Vec<String> {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain([header[i].chars().count()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |cells: &[&str]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(header)];
+    lines.extend(rows.iter().map(|row| {
+        let cells: Vec<&str> = row.iter().map(String::as_str).collect();
+        format_row(&cells)
+    }));
+    lines
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{aligned_table, Format};
+    use crate::{
+        day,
+        template::{
+            memory::MemoryUsage,
+            results::{parse_lines, PartResult, Status},
+        },
+    };
+
+    fn get_mock_results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                day: day!(1),
+                part: 1,
+                answer: Some("1,2".into()),
+                duration: Duration::from_micros(12),
+                samples: 1,
+                stats: None,
+                memory: Some(MemoryUsage {
+                    peak_bytes: 64,
+                    allocations: 2,
+                }),
+                status: Status::Solved,
+            },
+            PartResult {
+                day: day!(1),
+                part: 2,
+                answer: None,
+                duration: Duration::from_nanos(500),
+                samples: 1,
+                stats: None,
+                memory: None,
+                status: Status::Unsolved,
+            },
+        ]
+    }
+
+    #[test]
+    fn parses_formats() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("md".parse(), Ok(Format::Markdown));
+        assert_eq!("xml".parse::<Format>().is_err(), true);
+    }
+
+    #[test]
+    fn aligns_table_columns() {
+        let rows = vec![
+            vec!["1".to_string(), "µs".to_string(), "-".to_string()],
+            vec!["10".to_string(), "a".to_string(), String::new()],
+        ];
+
+        assert_eq!(
+            aligned_table(&["Day", "Time", "Note"], &rows),
+            vec!["Day  Time  Note", "1    µs    -", "10   a"]
+        );
+    }
+
+    #[test]
+    fn renders_csv() {
+        let expected = [
+            "day,part,answer,status,duration_nanos,samples,peak_bytes,allocations",
+            "01,1,\"1,2\",solved,12000,1,64,2",
+            "01,2,,unsolved,500,1,,",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(Format::Csv.render(&get_mock_results()), expected);
+    }
+
+    #[test]
+    fn renders_markdown() {
+        let expected = [
+            "| Day | Part | Answer | Status | Time |",
+            "| :---: | :---: | :---: | :---: | :---: |",
+            "| 01 | 1 | `1,2` | solved | 12.0µs |",
+            "| 01 | 2 | - | unsolved | 500.0ns |",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(Format::Markdown.render(&get_mock_results()), expected);
+    }
+
+    #[test]
+    fn renders_plain() {
+        let expected = [
+            "Day  Part  Answer  Status    Time",
+            "01   1     1,2     solved    12.0µs",
+            "01   2     -       unsolved  500.0ns",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(Format::Plain.render(&get_mock_results()), expected);
+    }
+
+    #[test]
+    fn renders_decoded_art_on_one_line() {
+        let mut results = get_mock_results();
+        results[1].answer = Some("I".into());
+        results[1].status = Status::Decoded("###\n.#.".into());
+
+        let plain = Format::Plain.render(&results);
+        assert!(plain.contains("I       decoded: ###\\n.#."));
+
+        let markdown = Format::Markdown.render(&results);
+        assert!(markdown.contains("| `I` | decoded: ###<br>.#. |"));
+    }
+
+    #[test]
+    fn renders_json() {
+        let results = get_mock_results();
+        let json = Format::Json.render(&results);
+        let parsed: tinyjson::JsonValue = json.parse().unwrap();
+        let lines: Vec<String> = parsed
+            .get::<Vec<tinyjson::JsonValue>>()
+            .unwrap()
+            .iter()
+            .map(|v| v.stringify().unwrap())
+            .collect();
+        assert_eq!(parse_lines(&lines.join("\n")).unwrap(), results);
+    }
+}
@@ -0,0 +1,330 @@
+/// Extracts example inputs and their expected answers from a puzzle description.
+/// Works on the article HTML that the built-in client writes to `data/YYYY/puzzles/DD.html`
+/// and on the markdown that aoc-cli writes to `data/YYYY/puzzles/DD.md`.
+use crate::template::aoc_client::{decode_entities, extract_articles};
+
+/// Example input and expected answer found in the description of one part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartExample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// Returns the examples of part one and part two. Part two is empty until it is unlocked.
+pub fn extract_examples(puzzle: &str) -> [PartExample; 2] {
+    let articles = extract_articles(puzzle);
+
+    if articles.is_empty() {
+        return extract_markdown_examples(puzzle);
+    }
+
+    [0, 1].map(|i| {
+        articles
+            .get(i)
+            .map(|article| PartExample {
+                input: code_blocks(article).into_iter().next(),
+                answer: emphasized_code(article).pop(),
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// Same as [`extract_examples`] for the markdown written by aoc-cli, where code blocks are fenced
+/// and answers are written as `` `*142*` `` or `` *`142`* ``.
+fn extract_markdown_examples(markdown: &str) -> [PartExample; 2] {
+    let mut parts = markdown.splitn(2, "--- Part Two ---");
+    let part_one = parts.next().unwrap_or_default();
+    let part_two = parts.next();
+
+    // the answer to part one is shown after its description once it is solved.
+    let part_one = part_one
+        .split("Your puzzle answer was")
+        .next()
+        .unwrap_or_default();
+
+    [Some(part_one), part_two].map(|part| {
+        part.map(|part| PartExample {
+            input: fenced_blocks(part).into_iter().next(),
+            answer: emphasized_markdown_code(part).pop(),
+        })
+        .unwrap_or_default()
+    })
+}
+
+/// Contents of all `<pre><code>` blocks, without markup.
+pub fn code_blocks(html: &str) -> Vec<String> {
+    elements(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(|block| decode_entities(&strip_tags(block)))
+        .collect()
+}
+
+/// Contents of all emphasized inline code, e.g. `<code><em>142</em></code>`.
+/// Advent of Code highlights the answers of its examples this way.
+pub fn emphasized_code(html: &str) -> Vec<String> {
+    let mut found: Vec<(usize, &str)> = vec![];
+
+    for (open, close) in [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ] {
+        let mut offset = 0;
+        while let Some(start) = html[offset..].find(open) {
+            let start = offset + start + open.len();
+            let Some(len) = html[start..].find(close) else {
+                break;
+            };
+            found.push((start, &html[start..start + len]));
+            offset = start + len;
+        }
+    }
+
+    found.sort_unstable_by_key(|(position, _)| *position);
+    found
+        .into_iter()
+        .map(|(_, s)| decode_entities(&strip_tags(s)))
+        .collect()
+}
+
+/// Contents of all fenced code blocks of a markdown document.
+fn fenced_blocks(markdown: &str) -> Vec<String> {
+    let mut found = vec![];
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        match (&mut block, line.trim_start().starts_with("```")) {
+            (None, true) => block = Some(String::new()),
+            (Some(_), true) => found.extend(block.take()),
+            (Some(block), false) => {
+                block.push_str(line);
+                block.push('\n');
+            }
+            (None, false) => {}
+        }
+    }
+
+    found
+}
+
+/// Markdown counterpart of [`emphasized_code`], skips fenced code blocks.
+fn emphasized_markdown_code(markdown: &str) -> Vec<String> {
+    let mut found = vec![];
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+        }
+        if in_block {
+            continue;
+        }
+
+        // every odd segment is inline code.
+        let segments: Vec<&str> = line.split('`').collect();
+        for i in (1..segments.len().saturating_sub(1)).step_by(2) {
+            let code = segments[i];
+            if let Some(inner) = code
+                .strip_prefix('*')
+                .and_then(|x| x.strip_suffix('*'))
+                .filter(|x| !x.is_empty())
+            {
+                found.push(inner.to_string());
+            } else if segments[i - 1].ends_with('*') && segments[i + 1].starts_with('*') {
+                found.push(code.to_string());
+            }
+        }
+    }
+
+    found
+}
+
+fn elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        let start = start + open.len();
+        let Some(len) = rest[start..].find(close) else {
+            break;
+        };
+        found.push(&rest[start..start + len]);
+        rest = &rest[start + len + close.len()..];
+    }
+
+    found
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/// Replace the `assert_eq!(result, None);` of a generated test with the expected answer.
+/// Returns [`None`] if the test was already changed.
+pub fn patch_assertion(source: &str, test_name: &str, answer: &str) -> Option<String> {
+    let expected = if answer.parse::<i128>().is_ok() {
+        answer.to_string()
+    } else {
+        format!("{answer:?}.to_string()")
+    };
+
+    replace_in_test(
+        source,
+        test_name,
+        "assert_eq!(result, None);",
+        &format!("assert_eq!(result, Some({expected}));"),
+    )
+}
+
+/// Replace the first occurrence of `from` inside the body of a test function.
+pub fn replace_in_test(source: &str, test_name: &str, from: &str, to: &str) -> Option<String> {
+    let test_start = source.find(&format!("fn {test_name}()"))?;
+    let test_end = source[test_start..]
+        .find("\n    }")
+        .map_or(source.len(), |x| test_start + x);
+    let position = test_start + source[test_start..test_end].find(from)?;
+
+    Some(format!(
+        "{}{to}{}",
+        &source[..position],
+        &source[position + from.len()..]
+    ))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_examples, patch_assertion, replace_in_test, PartExample};
+
+    const PUZZLE: &str = r#"<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+</code></pre>
+<p>In this example, the calibration values are <code>12</code> and <code>38</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+<em>eight</em>wothree
+&lt;x&gt;
+</code></pre>
+<p>Adding these together produces <em><code>281</code></em>.</p>
+</article>"#;
+
+    #[test]
+    fn extracts_examples_of_both_parts() {
+        let [part_one, part_two] = extract_examples(PUZZLE);
+
+        assert_eq!(
+            part_one,
+            PartExample {
+                input: Some("1abc2\npqr3stu8vwx\na1b2c3d4e5f\n".into()),
+                answer: Some("142".into()),
+            }
+        );
+
+        assert_eq!(
+            part_two,
+            PartExample {
+                input: Some("two1nine\neightwothree\n<x>\n".into()),
+                answer: Some("281".into()),
+            }
+        );
+    }
+
+    const MARKDOWN: &str = r#"\--- Day 1: Trebuchet?! ---
+----------
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+```
+
+In this example, the calibration values are `12` and `38`. Adding these together produces `*142*`.
+
+Your puzzle answer was `54331`.
+
+\--- Part Two ---
+----------
+
+```
+two1nine
+eightwothree
+```
+
+Adding these together produces *`281`*.
+"#;
+
+    #[test]
+    fn extracts_examples_from_markdown() {
+        let [part_one, part_two] = extract_examples(MARKDOWN);
+
+        assert_eq!(
+            part_one,
+            PartExample {
+                input: Some("1abc2\npqr3stu8vwx\na1b2c3d4e5f\n".into()),
+                answer: Some("142".into()),
+            }
+        );
+
+        assert_eq!(
+            part_two,
+            PartExample {
+                input: Some("two1nine\neightwothree\n".into()),
+                answer: Some("281".into()),
+            }
+        );
+
+        let locked = MARKDOWN.split("Your puzzle").next().unwrap();
+        assert_eq!(extract_examples(locked)[1], PartExample::default());
+    }
+
+    #[test]
+    fn handles_locked_part_two() {
+        let html = PUZZLE.split("<p>Your puzzle").next().unwrap();
+        let [_, part_two] = extract_examples(html);
+        assert_eq!(part_two, PartExample::default());
+    }
+
+    #[test]
+    fn patches_generated_assertions() {
+        let source = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+        let patched = patch_assertion(source, "test_part_two", "281").unwrap();
+        assert!(patched.contains("assert_eq!(result, Some(281));"));
+        assert_eq!(patched.matches("assert_eq!(result, None);").count(), 1);
+
+        let patched = patch_assertion(&patched, "test_part_one", "ABC").unwrap();
+        assert!(patched.contains(r#"assert_eq!(result, Some("ABC".to_string()));"#));
+
+        assert_eq!(patch_assertion(&patched, "test_part_one", "1"), None);
+
+        let patched = replace_in_test(
+            &patched,
+            "test_part_two",
+            "read_file(\"examples\", YEAR, DAY)",
+            "read_file_part(\"examples\", YEAR, DAY, 2)",
+        )
+        .unwrap();
+        assert!(patched.contains(
+            "part_two(&advent_of_code::template::read_file_part(\"examples\", YEAR, DAY, 2))"
+        ));
+    }
+}
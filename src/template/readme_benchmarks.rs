@@ -1,12 +1,22 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fmt::Display, fs, io, str::FromStr, time::Duration};
 
+use regex::Regex;
+
+use crate::template::memory::{format_bytes, MemoryUsage};
+use crate::template::results::PARSE_STEP;
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::{Day, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
+/// Matches the marker, optionally with a format, e.g. `<!--- benchmarking table format=bars --->`.
+static MARKER_PATTERN: &str = r"<!--- benchmarking table(?: format=([a-z]+))? --->";
+
+/// Number of characters of the longest bar in [`TableFormat::Bars`].
+const BAR_WIDTH: usize = 20;
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
@@ -20,18 +30,82 @@ impl From<std::io::Error> for Error {
     }
 }
 
+/// Layout of the benchmarks in the README.
+/// Selected with the `format` attribute of the marker or `cargo time --table-format`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableFormat {
+    /// One row per day with the time of each part.
+    #[default]
+    Table,
+    /// One row per part with the median, minimum, samples and peak memory.
+    Stats,
+    /// One row per day with a bar relative to the slowest day.
+    Bars,
+    /// Like [`TableFormat::Table`], rendered as an HTML table.
+    Html,
+}
+
+impl Display for TableFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableFormat::Table => write!(f, "table"),
+            TableFormat::Stats => write!(f, "stats"),
+            TableFormat::Bars => write!(f, "bars"),
+            TableFormat::Html => write!(f, "html"),
+        }
+    }
+}
+
+impl FromStr for TableFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(TableFormat::Table),
+            "stats" => Ok(TableFormat::Stats),
+            "bars" => Ok(TableFormat::Bars),
+            "html" => Ok(TableFormat::Html),
+            s => Err(format!(
+                "unknown table format `{s}`, expected one of table, stats, bars or html."
+            )),
+        }
+    }
+}
+
+impl TableFormat {
+    /// The marker that encloses a table of this format. The default format is written without attribute.
+    fn marker(self) -> String {
+        match self {
+            TableFormat::Table => MARKER.into(),
+            format => format!("<!--- benchmarking table format={format} --->"),
+        }
+    }
+
+    fn render_rows(self, year: Year, timings: &Timings) -> Vec<String> {
+        match self {
+            TableFormat::Table => render_table(year, timings),
+            TableFormat::Stats => render_stats(year, timings),
+            TableFormat::Bars => render_bars(year, timings),
+            TableFormat::Html => render_html(year, timings),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
+    /// Format set in the marker, if any.
+    format: Option<TableFormat>,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}_{day}.rs")
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+    let pattern = Regex::new(MARKER_PATTERN).unwrap();
+    let matches: Vec<_> = pattern.captures_iter(readme).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -39,89 +113,320 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
         ));
     }
 
-    let pos_start = matches
+    let first = matches
         .first()
-        .map(|m| m.0)
         .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
 
+    let pos_start = first.get(0).map_or(0, |m| m.start());
+
     let pos_end = matches
         .last()
-        .map(|m| m.0 + m.1.len())
+        .and_then(|m| m.get(0))
+        .map(|m| m.end())
         .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
 
-    Ok(TablePosition { pos_start, pos_end })
+    let format = first
+        .get(1)
+        .map(|m| m.as_str().parse::<TableFormat>())
+        .transpose()
+        .map_err(Error::Parser)?;
+
+    Ok(TablePosition {
+        pos_start,
+        pos_end,
+        format,
+    })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    timings: &Timings,
+    total_millis: f64,
+    format: TableFormat,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    let mut lines: Vec<String> = vec![format.marker(), header, String::new()];
+    lines.extend(format.render_rows(year, timings));
+    lines.push(String::new());
+    lines.push(format!("**Total: {total_millis:.2}ms**"));
+    lines.push(format.marker());
+
+    lines.join("\n")
+}
+
+fn render_table(year: Year, timings: &Timings) -> Vec<String> {
+    // the parse column is only shown once a day declares a separate parse step.
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+    // memory columns are only shown if timings were recorded with the counting allocator.
+    let has_memory = timings
+        .data
+        .iter()
+        .any(|t| t.part_1_memory.is_some() || t.part_2_memory.is_some());
+
+    let mut lines: Vec<String> = vec![];
+
+    match (has_parse, has_memory) {
+        (true, true) => {
+            lines.push("| Day | Parse | Part 1 | Part 2 | Part 1 Memory | Part 2 Memory |".into());
+            lines.push("| :---: | :---: | :---: | :---: | :---: | :---:  |".into());
+        }
+        (true, false) => {
+            lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+            lines.push("| :---: | :---: | :---: | :---:  |".into());
+        }
+        (false, true) => {
+            lines.push("| Day | Part 1 | Part 2 | Part 1 Memory | Part 2 Memory |".into());
+            lines.push("| :---: | :---: | :---: | :---: | :---:  |".into());
+        }
+        (false, false) => {
+            lines.push("| Day | Part 1 | Part 2 |".into());
+            lines.push("| :---: | :---: | :---:  |".into());
+        }
+    }
+
+    for timing in &timings.data {
+        let path = get_path_for_bin(year, timing.day);
+        let parse = if has_parse {
+            format!(" `{}` |", timing.parse.as_deref().unwrap_or("-"))
+        } else {
+            String::new()
+        };
+        let memory = if has_memory {
+            format!(
+                " `{}` | `{}` |",
+                format_memory(timing.part_1_memory.as_ref()),
+                format_memory(timing.part_2_memory.as_ref())
+            )
+        } else {
+            String::new()
+        };
+        lines.push(format!(
+            "| [Day {}]({}) |{} `{}` | `{}` |{}",
+            timing.day.into_inner(),
+            path,
+            parse,
+            timing.part_1.as_deref().unwrap_or("-"),
+            timing.part_2.as_deref().unwrap_or("-"),
+            memory
+        ));
+    }
+
+    lines
+}
+
+fn render_stats(year: Year, timings: &Timings) -> Vec<String> {
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| Day | Part | Median | Min | Samples | Peak Memory |".into(),
+        "| :---: | :---: | :---: | :---: | :---: | :---: |".into(),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+    for timing in &timings.data {
+        let path = get_path_for_bin(year, timing.day);
+
+        let steps = [
+            (
+                PARSE_STEP,
+                &timing.parse,
+                &timing.parse_stats,
+                &timing.parse_memory,
+            ),
+            (
+                1,
+                &timing.part_1,
+                &timing.part_1_stats,
+                &timing.part_1_memory,
+            ),
+            (
+                2,
+                &timing.part_2,
+                &timing.part_2_stats,
+                &timing.part_2_memory,
+            ),
+        ];
+
+        for (step, duration, stats, memory) in steps {
+            let Some(duration) = duration else {
+                continue;
+            };
+
+            let name = match step {
+                PARSE_STEP => "Parse".to_string(),
+                part => part.to_string(),
+            };
+
+            // timings stored without stats only know the duration of a single run.
+            let (median, min, samples) = match stats {
+                Some(stats) => (
+                    format!("{:.1?}", stats.median),
+                    format!("`{:.1?}`", stats.min),
+                    stats.samples.to_string(),
+                ),
+                None => (duration.clone(), "-".into(), "1".into()),
+            };
+
+            lines.push(format!(
+                "| [Day {}]({}) | {} | `{}` | {} | {} | `{}` |",
+                timing.day.into_inner(),
+                path,
+                name,
+                median,
+                min,
+                samples,
+                format_memory(memory.as_ref())
+            ));
+        }
+    }
+
+    lines
+}
+
+fn render_bars(year: Year, timings: &Timings) -> Vec<String> {
+    let max_nanos = timings
+        .data
+        .iter()
+        .map(|t| t.total_nanos)
+        .fold(0_f64, f64::max);
+
+    let mut lines: Vec<String> = vec!["| Day | Time | |".into(), "| :---: | :---: | :--- |".into()];
+
+    for timing in &timings.data {
+        let fraction = if max_nanos > 0_f64 {
+            timing.total_nanos / max_nanos
+        } else {
+            0_f64
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let total = Duration::from_nanos(timing.total_nanos as u64);
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{:.1?}` | `{}` |",
             timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            get_path_for_bin(year, timing.day),
+            total,
+            bar(fraction, BAR_WIDTH)
         ));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines
+}
 
-    lines.join("\n")
+fn render_html(year: Year, timings: &Timings) -> Vec<String> {
+    let has_parse = timings.data.iter().any(|t| t.parse.is_some());
+
+    let mut lines: Vec<String> = vec!["<table>".into()];
+
+    let mut header = String::from("  <tr><th>Day</th>");
+    if has_parse {
+        header.push_str("<th>Parse</th>");
+    }
+    header.push_str("<th>Part 1</th><th>Part 2</th></tr>");
+    lines.push(header);
+
+    for timing in &timings.data {
+        let cell = |value: &Option<String>| {
+            format!("<td><code>{}</code></td>", value.as_deref().unwrap_or("-"))
+        };
+
+        let mut row = format!(
+            "  <tr><td><a href=\"{}\">Day {}</a></td>",
+            get_path_for_bin(year, timing.day),
+            timing.day.into_inner()
+        );
+        if has_parse {
+            row.push_str(&cell(&timing.parse));
+        }
+        row.push_str(&cell(&timing.part_1));
+        row.push_str(&cell(&timing.part_2));
+        row.push_str("</tr>");
+        lines.push(row);
+    }
+
+    lines.push("</table>".into());
+    lines
+}
+
+fn format_memory(memory: Option<&MemoryUsage>) -> String {
+    memory.map_or_else(|| "-".into(), |m| format_bytes(m.peak_bytes))
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+/// A horizontal bar of `fraction * width` characters, using eighth blocks for the remainder.
+fn bar(fraction: f64, width: usize) -> String {
+    const PARTIAL: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    let eighths = (fraction.clamp(0_f64, 1_f64) * (width * 8) as f64).round() as usize;
+
+    let mut bar = "█".repeat(eighths / 8);
+    let remainder = eighths % 8;
+    if remainder > 0 {
+        bar.push(PARTIAL[remainder]);
+    }
+
+    // pad to a fixed width so that the code spans line up.
+    let padding = width.saturating_sub(bar.chars().count());
+    bar + &" ".repeat(padding)
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: &Timings,
+    total_millis: f64,
+    format: Option<TableFormat>,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let format = format.or(positions.format).unwrap_or_default();
+    let table = construct_table("##", year, timings, total_millis, format);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Update the benchmarks in the README. Uses the format of the marker unless `format` is passed,
+/// in which case the marker is updated to that format.
+pub fn update(year: Year, timings: Timings, format: Option<TableFormat>) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, &timings, total_millis, format)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{update_content, TableFormat, MARKER};
+    use crate::{
+        day, template::memory::MemoryUsage, template::timings::Timing, template::timings::Timings,
+        year,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -131,28 +436,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), &get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), &get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), &get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), &get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, year!(2024), &get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +465,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, year!(2024), &get_mock_timings(), 190.0, None).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +474,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024_04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +485,169 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_parse_step() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some("5ms".into());
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2024), &timings, 190.0, None).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2024_01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024_02.rs) | `5ms` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024_04.rs) | `-` | `40ms` | `50ms` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1_memory = Some(MemoryUsage {
+            peak_bytes: 1536,
+            allocations: 3,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, year!(2024), &timings, 190.0, None).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Part 1 | Part 2 | Part 1 Memory | Part 2 Memory |",
+            "| :---: | :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2024_01.rs) | `10ms` | `20ms` | `1.5 KiB` | `-` |",
+            "| [Day 2](./src/bin/2024_02.rs) | `30ms` | `40ms` | `-` | `-` |",
+            "| [Day 4](./src/bin/2024_04.rs) | `40ms` | `50ms` | `-` | `-` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn errors_if_marker_format_unknown() {
+        let mut s =
+            "<!--- benchmarking table format=xml --->\n<!--- benchmarking table format=xml --->"
+                .to_string();
+        let res = update_content(&mut s, year!(2024), &get_mock_timings(), 190.0, None);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn format_benchmarks_as_bars_from_marker() {
+        let marker = "<!--- benchmarking table format=bars --->";
+        let mut s = format!("{}\n{}", marker, marker);
+        update_content(&mut s, year!(2024), &get_mock_timings(), 190.0, None).unwrap();
+        let expected = [
+            "<!--- benchmarking table format=bars --->",
+            "## Benchmarks",
+            "",
+            "| Day | Time | |",
+            "| :---: | :---: | :--- |",
+            "| [Day 1](./src/bin/2024_01.rs) | `30.0s` | `██████▋             ` |",
+            "| [Day 2](./src/bin/2024_02.rs) | `70.0s` | `███████████████▌    ` |",
+            "| [Day 4](./src/bin/2024_04.rs) | `90.0s` | `████████████████████` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table format=bars --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_as_html() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            year!(2024),
+            &get_mock_timings(),
+            190.0,
+            Some(TableFormat::Html),
+        )
+        .unwrap();
+        let expected = [
+            "<!--- benchmarking table format=html --->",
+            "## Benchmarks",
+            "",
+            "<table>",
+            "  <tr><th>Day</th><th>Part 1</th><th>Part 2</th></tr>",
+            "  <tr><td><a href=\"./src/bin/2024_01.rs\">Day 1</a></td><td><code>10ms</code></td><td><code>20ms</code></td></tr>",
+            "  <tr><td><a href=\"./src/bin/2024_02.rs\">Day 2</a></td><td><code>30ms</code></td><td><code>40ms</code></td></tr>",
+            "  <tr><td><a href=\"./src/bin/2024_04.rs\">Day 4</a></td><td><code>40ms</code></td><td><code>50ms</code></td></tr>",
+            "</table>",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table format=html --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_benchmarks_as_stats() {
+        let mut timings = get_mock_timings();
+        timings.data.truncate(1);
+        timings.data[0].parse = Some("5ms".into());
+        timings.data[0].part_1_memory = Some(MemoryUsage {
+            peak_bytes: 2048,
+            allocations: 1,
+        });
+
+        let mut s =
+            "<!--- benchmarking table format=bars --->\n<!--- benchmarking table format=bars --->"
+                .to_string();
+        update_content(
+            &mut s,
+            year!(2024),
+            &timings,
+            35.0,
+            Some(TableFormat::Stats),
+        )
+        .unwrap();
+        let expected = [
+            "<!--- benchmarking table format=stats --->",
+            "## Benchmarks",
+            "",
+            "| Day | Part | Median | Min | Samples | Peak Memory |",
+            "| :---: | :---: | :---: | :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2024_01.rs) | Parse | `5ms` | - | 1 | `-` |",
+            "| [Day 1](./src/bin/2024_01.rs) | 1 | `10ms` | - | 1 | `2.0 KiB` |",
+            "| [Day 1](./src/bin/2024_01.rs) | 2 | `20ms` | - | 1 | `-` |",
+            "",
+            "**Total: 35.00ms**",
+            "<!--- benchmarking table format=stats --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_override_resets_marker() {
+        let marker = "<!--- benchmarking table format=bars --->";
+        let mut s = format!("{}\n{}", marker, marker);
+        update_content(
+            &mut s,
+            year!(2024),
+            &get_mock_timings(),
+            190.0,
+            Some(TableFormat::Table),
+        )
+        .unwrap();
+        assert!(s.starts_with(&format!("{MARKER}\n")));
+        assert!(s.ends_with(&format!("\n{MARKER}")));
+    }
 }
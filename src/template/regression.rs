@@ -0,0 +1,180 @@
+/// Compares fresh benchmark times against the stored ones to spot performance regressions.
+use std::time::Duration;
+
+use crate::template::{
+    results::PARSE_STEP,
+    timings::{Timing, Timings},
+    Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Default relative slowdown (in percent) above which a change counts as a regression.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Change in execution time of a single step (parse or part) of a day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delta {
+    pub day: Day,
+    pub step: u8,
+    pub old_nanos: f64,
+    pub new_nanos: f64,
+}
+
+impl Delta {
+    /// Relative change in percent, positive values mean that the step got slower.
+    pub fn change_percent(&self) -> f64 {
+        if self.old_nanos == 0_f64 {
+            return 0_f64;
+        }
+        (self.new_nanos - self.old_nanos) / self.old_nanos * 100_f64
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent() > threshold
+    }
+}
+
+/// Pair up all steps that are present in both the stored and the fresh timings.
+pub fn compare(stored: &Timings, fresh: &Timings) -> Vec<Delta> {
+    fresh
+        .data
+        .iter()
+        .filter_map(|new| {
+            let old = stored.data.iter().find(|t| t.day == new.day)?;
+            Some((old, new))
+        })
+        .flat_map(|(old, new)| {
+            [PARSE_STEP, 1, 2]
+                .into_iter()
+                .filter_map(|step| delta(old, new, step))
+        })
+        .collect()
+}
+
+fn delta(old: &Timing, new: &Timing, step: u8) -> Option<Delta> {
+    Some(Delta {
+        day: new.day,
+        step,
+        old_nanos: old.step_nanos(step)?,
+        new_nanos: new.step_nanos(step)?,
+    })
+}
+
+/// Print one line per delta and return whether any of them is a regression.
+pub fn print_report(deltas: &[Delta], threshold: f64) -> bool {
+    println!();
+
+    if deltas.is_empty() {
+        println!("{ANSI_ITALIC}No stored timings to compare against.{ANSI_RESET}");
+        return false;
+    }
+
+    println!(
+        "{ANSI_BOLD}Compared to stored timings{ANSI_RESET} \
+        {ANSI_ITALIC}(threshold: {threshold}%){ANSI_RESET}"
+    );
+
+    let mut has_regression = false;
+
+    for delta in deltas {
+        let step = match delta.step {
+            PARSE_STEP => "Parse ".to_string(),
+            part => format!("Part {part}"),
+        };
+
+        let line = format!(
+            "Day {} {step}: {} → {} ({:+.1}%)",
+            delta.day,
+            format_nanos(delta.old_nanos),
+            format_nanos(delta.new_nanos),
+            delta.change_percent()
+        );
+
+        if delta.is_regression(threshold) {
+            has_regression = true;
+            println!("{ANSI_BOLD}{line} ▲ regression{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+
+    has_regression
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, Delta};
+    use crate::{
+        day,
+        template::{
+            timings::{Timing, Timings},
+            Day,
+        },
+    };
+
+    fn timing(day: u8, part_1: Option<&str>, part_2: Option<&str>) -> Timing {
+        Timing {
+            part_1: part_1.map(Into::into),
+            part_2: part_2.map(Into::into),
+            ..Timing::new(Day::new(day).unwrap())
+        }
+    }
+
+    #[test]
+    fn compares_matching_steps() {
+        let stored = Timings {
+            data: vec![
+                timing(1, Some("10.0ms"), Some("20.0ms")),
+                timing(2, Some("1.0ms"), None),
+            ],
+        };
+
+        let fresh = Timings {
+            data: vec![
+                timing(1, Some("12.0ms"), Some("10.0ms")),
+                timing(2, Some("1.0ms"), Some("5.0ms")),
+                timing(3, Some("1.0ms"), Some("1.0ms")),
+            ],
+        };
+
+        let deltas = compare(&stored, &fresh);
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].day, day!(1));
+        assert_eq!(deltas[0].step, 1);
+        assert_eq!(deltas[1].step, 2);
+        assert_eq!(deltas[2].day, day!(2));
+    }
+
+    #[test]
+    fn detects_regressions_above_threshold() {
+        let delta = Delta {
+            day: day!(1),
+            step: 1,
+            old_nanos: 100.0,
+            new_nanos: 115.0,
+        };
+
+        assert_eq!(delta.change_percent(), 15.0);
+        assert_eq!(delta.is_regression(10.0), true);
+        assert_eq!(delta.is_regression(20.0), false);
+    }
+
+    #[test]
+    fn improvements_are_not_regressions() {
+        let delta = Delta {
+            day: day!(1),
+            step: 2,
+            old_nanos: 100.0,
+            new_nanos: 50.0,
+        };
+
+        assert_eq!(delta.change_percent(), -50.0);
+        assert_eq!(delta.is_regression(0.0), false);
+    }
+}
@@ -0,0 +1,439 @@
+/// Machine-readable results of running solution parts.
+/// If the `AOC_RESULTS_FILE` environment variable is set, every part that is run appends one JSON line to that file.
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::OpenOptions,
+    io::{self, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::{
+    ocr,
+    template::{memory::MemoryUsage, stats::Stats, Day},
+};
+
+pub static RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+/// Part number used for the parse step of solutions declared with `solution!(DAY, parse = ...)`.
+pub const PARSE_STEP: u8 = 0;
+
+/// Whether a part produced an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    /// The answer is a multiline picture that could not be decoded and has to be read before submitting.
+    Art,
+    /// The answer was decoded from a multiline picture, contains the picture.
+    Decoded(String),
+    /// The answer was marked with [`Inspect`] and has to be checked by hand before submitting.
+    Inspect,
+    /// The part returned an error, contains its message.
+    Failed(String),
+    /// The part did not finish within the configured timeout.
+    Timeout,
+    /// The part panicked, contains the panic message.
+    Panicked(String),
+}
+
+impl Status {
+    /// Whether the part produced an answer, even if it can't be submitted as-is.
+    pub fn has_answer(&self) -> bool {
+        matches!(
+            self,
+            Status::Solved | Status::Art | Status::Decoded(_) | Status::Inspect
+        )
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Art => write!(f, "art"),
+            Status::Decoded(art) => write!(f, "decoded: {art}"),
+            Status::Inspect => write!(f, "inspect"),
+            Status::Failed(message) => write!(f, "failed: {message}"),
+            Status::Timeout => write!(f, "timeout"),
+            Status::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "unsolved" => Ok(Status::Unsolved),
+            "art" => Ok(Status::Art),
+            "inspect" => Ok(Status::Inspect),
+            "timeout" => Ok(Status::Timeout),
+            s => {
+                if let Some(message) = s.strip_prefix("panicked: ") {
+                    Ok(Status::Panicked(message.into()))
+                } else if let Some(message) = s.strip_prefix("failed: ") {
+                    Ok(Status::Failed(message.into()))
+                } else if let Some(art) = s.strip_prefix("decoded: ") {
+                    Ok(Status::Decoded(art.into()))
+                } else {
+                    Err(format!("unknown status `{s}`."))
+                }
+            }
+        }
+    }
+}
+
+/// An answer that has to be checked by hand before submitting, e.g. because the solution
+/// relies on a heuristic. Return it as `Some(Inspect(answer))` or `Ok(Inspect(answer))`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Inspect<T>(pub T);
+
+/// What a solution part returned, see [`IntoAnswer`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Unsolved,
+    Value(String),
+    /// A multiline answer, e.g. ASCII art. Contains the letters if they could be recognized, see [`crate::ocr`].
+    Art {
+        art: String,
+        decoded: Option<String>,
+    },
+    Inspect(String),
+    /// The part returned an error, contains its message.
+    Error(String),
+}
+
+impl Answer {
+    fn from_display(value: &impl Display) -> Self {
+        let value = value.to_string();
+        if value.contains('\n') {
+            Answer::Art {
+                decoded: ocr::decode(&value),
+                art: value,
+            }
+        } else {
+            Answer::Value(value)
+        }
+    }
+
+    /// The answer as a string and the status it is reported with.
+    /// Decoded art is answered with its letters and keeps the picture in the status.
+    pub fn into_parts(self) -> (Option<String>, Status) {
+        match self {
+            Answer::Unsolved => (None, Status::Unsolved),
+            Answer::Value(value) => (Some(value), Status::Solved),
+            Answer::Art {
+                art,
+                decoded: Some(decoded),
+            } => (Some(decoded), Status::Decoded(art)),
+            Answer::Art { art, decoded: None } => (Some(art), Status::Art),
+            Answer::Inspect(value) => (Some(value), Status::Inspect),
+            Answer::Error(message) => (None, Status::Failed(message)),
+        }
+    }
+}
+
+/// A value that a solution part can produce, i.e. anything that implements [`Display`] or an [`Inspect`].
+pub trait AnswerValue {
+    fn to_answer(&self) -> Answer;
+}
+
+impl<T: Display> AnswerValue for T {
+    fn to_answer(&self) -> Answer {
+        Answer::from_display(self)
+    }
+}
+
+impl<T: Display> AnswerValue for Inspect<T> {
+    fn to_answer(&self) -> Answer {
+        Answer::Inspect(self.0.to_string())
+    }
+}
+
+/// Return types supported for solution parts: `Option<T>`, where `None` means unsolved,
+/// and `Result<T, E>`, where the error message is shown.
+pub trait IntoAnswer {
+    fn to_answer(&self) -> Answer;
+}
+
+impl<T: AnswerValue> IntoAnswer for Option<T> {
+    fn to_answer(&self) -> Answer {
+        self.as_ref()
+            .map_or(Answer::Unsolved, AnswerValue::to_answer)
+    }
+}
+
+impl<T: AnswerValue, E: Display> IntoAnswer for Result<T, E> {
+    fn to_answer(&self) -> Answer {
+        match self {
+            Ok(value) => value.to_answer(),
+            Err(e) => Answer::Error(e.to_string()),
+        }
+    }
+}
+
+/// The outcome of running a single part (or the parse step) of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    /// Only present if the part was benched.
+    pub stats: Option<Stats>,
+    /// Only present if the counting allocator is installed, see [`crate::template::memory`].
+    pub memory: Option<MemoryUsage>,
+    pub status: Status,
+}
+
+impl PartResult {
+    /// Append this result as a JSON line to the file in `AOC_RESULTS_FILE`, if set.
+    pub fn emit(&self) -> Result<(), io::Error> {
+        let Ok(path) = env::var(RESULTS_FILE_ENV) else {
+            return Ok(());
+        };
+
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{line}")
+    }
+}
+
+/// Parse results that were emitted as JSON lines. Empty lines are ignored.
+pub fn parse_lines(s: &str) -> Result<Vec<PartResult>, String> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err(format!("not valid JSON: {line}")))?;
+            PartResult::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        let duration_nanos = value.duration.as_nanos() as f64;
+        map.insert("duration_nanos".into(), JsonValue::Number(duration_nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        let samples = value.samples as f64;
+        map.insert("samples".into(), JsonValue::Number(samples));
+
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "memory".into(),
+            match &value.memory {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected result to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected result.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected result.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?;
+
+        let duration_nanos = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected result.duration_nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected result.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(Stats::try_from(v)?),
+            _ => None,
+        };
+
+        let memory = match json.get("memory") {
+            Some(v) if !v.is_null() => Some(MemoryUsage::try_from(v)?),
+            _ => None,
+        };
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected result.status to be a string.")?
+            .parse()?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartResult {
+            day,
+            part: *part as u8,
+            answer: answer.cloned(),
+            duration: Duration::from_nanos(*duration_nanos as u64),
+            samples: *samples as u128,
+            stats,
+            memory,
+            status,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{parse_lines, Answer, Inspect, IntoAnswer, PartResult, Status};
+    use crate::day;
+
+    fn get_mock_result() -> PartResult {
+        PartResult {
+            day: day!(1),
+            part: 2,
+            answer: Some("@ ( ) ms 10 samples)".into()),
+            duration: Duration::from_nanos(74_130),
+            samples: 100,
+            stats: None,
+            memory: None,
+            status: Status::Solved,
+        }
+    }
+
+    #[test]
+    fn roundtrips_results() {
+        let result = get_mock_result();
+        let line = JsonValue::from(&result).stringify().unwrap();
+        assert_eq!(line.contains('\n'), false);
+        assert_eq!(parse_lines(&line).unwrap(), vec![result]);
+    }
+
+    #[test]
+    fn parses_multiple_lines() {
+        let lines = [
+            r#"{ "day": "01", "part": 1, "answer": null, "duration_nanos": 10, "samples": 1, "status": "unsolved" }"#,
+            "",
+            r#"{ "day": "01", "part": 2, "answer": "a\nb", "duration_nanos": 2000000000, "samples": 5, "status": "solved" }"#,
+        ]
+        .join("\n");
+
+        let results = parse_lines(&lines).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer, None);
+        assert_eq!(results[0].status, Status::Unsolved);
+        assert_eq!(results[1].answer, Some("a\nb".into()));
+        assert_eq!(results[1].duration, Duration::from_secs(2));
+        assert_eq!(results[1].samples, 5);
+    }
+
+    #[test]
+    fn roundtrips_statuses() {
+        for status in [
+            Status::Art,
+            Status::Decoded("#.\n.#".into()),
+            Status::Inspect,
+            Status::Failed("no path found".into()),
+            Status::Timeout,
+            Status::Panicked("index out of bounds: the len is 3".into()),
+        ] {
+            let result = PartResult {
+                status,
+                ..get_mock_result()
+            };
+            let line = JsonValue::from(&result).stringify().unwrap();
+            assert_eq!(parse_lines(&line).unwrap(), vec![result]);
+        }
+    }
+
+    #[test]
+    fn converts_return_types_to_answers() {
+        assert_eq!(Some(42).to_answer(), Answer::Value("42".into()));
+        assert_eq!(None::<u32>.to_answer(), Answer::Unsolved);
+        assert_eq!(
+            Some("#.\n.#").to_answer(),
+            Answer::Art {
+                art: "#.\n.#".into(),
+                decoded: None
+            }
+        );
+
+        let art = "###\n.#.\n.#.\n.#.\n.#.\n###";
+        assert_eq!(
+            Some(art).to_answer().into_parts(),
+            (Some("I".into()), Status::Decoded(art.into()))
+        );
+        assert_eq!(Some(Inspect(7)).to_answer(), Answer::Inspect("7".into()));
+
+        let ok: Result<u32, String> = Ok(1);
+        assert_eq!(ok.to_answer(), Answer::Value("1".into()));
+        let err: Result<u32, String> = Err("no path found".into());
+        assert_eq!(err.to_answer(), Answer::Error("no path found".into()));
+        assert_eq!(
+            err.to_answer().into_parts(),
+            (None, Status::Failed("no path found".into()))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_results() {
+        parse_lines(r#"{ "day": "01", "part": 1 }"#).unwrap();
+    }
+}
@@ -1,14 +1,44 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    output::aligned_table,
+    results::{PartResult, Status, PARSE_STEP},
+    runner::{run_solution, RunOptions, Solution},
+    try_read_file, Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
 
 use super::{
     all_days,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Run the given days in-process by calling their registered [`Solution`].
+/// If `options.format` is set, the results of all days are printed in that format at the end.
+///
+/// Returns the timings of a timed run, unless a part timed out. Its thread keeps running in
+/// the background, so the timings taken after it are not reliable.
+pub fn run_multi(
+    solutions: &[Solution],
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: &RunOptions,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut all_results: Vec<PartResult> = vec![];
+
+    let format = options.format;
+    let options = &RunOptions {
+        quiet: options.quiet || format.is_some(),
+        ..options.clone()
+    };
 
     let mut need_space = false;
 
@@ -16,242 +46,459 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     all_days()
         .filter(|day| days_to_run.contains(day))
         .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+            if format.is_none() {
+                if need_space {
+                    println!();
+                }
+                need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+                println!("------");
+            }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            let Some(solution) = solutions.iter().find(|s| s.year == year && s.day == day) else {
+                if format.is_none() {
+                    println!("Not solved.");
+                }
+                return;
+            };
+
+            let input = match try_read_file("inputs", year, day) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Could not read input file: {e}");
+                    return;
+                }
+            };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
+            let results = run_solution(solution, &input, options);
+            let timing = timing_from_results(day, &results);
+            timings.push(timing);
+            all_results.extend(results);
         });
 
-    if is_timed {
+    if let Some(format) = format {
+        print!("{}", format.render(&all_results));
+    }
+
+    let timed_out = all_results.iter().any(|r| r.status == Status::Timeout);
+
+    if options.is_timed && !timed_out {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
+        if format.is_none() {
+            println!(
+                "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
         Some(timings)
     } else {
         None
     }
 }
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    IO(io::Error),
+/// What happened when running a day in parallel.
+#[derive(Clone, Debug)]
+enum DayOutcome {
+    /// No solution is registered for the day.
+    NotSolved,
+    NoInput(String),
+    Ran(Vec<PartResult>),
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+impl DayOutcome {
+    fn status(&self) -> &'static str {
+        match self {
+            DayOutcome::NotSolved => "not solved",
+            DayOutcome::NoInput(_) => "no input",
+            DayOutcome::Ran(results) => {
+                if results
+                    .iter()
+                    .any(|r| matches!(r.status, Status::Panicked(_)))
+                {
+                    return "panicked";
+                }
+                if results.iter().any(|r| r.status == Status::Timeout) {
+                    return "timeout";
+                }
+                if results
+                    .iter()
+                    .any(|r| matches!(r.status, Status::Failed(_)))
+                {
+                    return "error";
+                }
+
+                let solved = results
+                    .iter()
+                    .filter(|r| r.part != PARSE_STEP && r.status.has_answer())
+                    .count();
+                let parts = results.iter().filter(|r| r.part != PARSE_STEP).count();
+
+                if solved == parts {
+                    if results.iter().any(|r| r.status == Status::Inspect) {
+                        "needs inspection"
+                    } else if results.iter().any(|r| r.status == Status::Art) {
+                        "art"
+                    } else {
+                        "solved"
+                    }
+                } else if solved > 0 {
+                    "partial"
+                } else {
+                    "unsolved"
+                }
+            }
+        }
     }
-}
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    fn answer(&self, part: u8) -> String {
+        let DayOutcome::Ran(results) = self else {
+            return "-".into();
+        };
+
+        let Some(result) = results.iter().find(|r| r.part == part) else {
+            return "-".into();
+        };
+
+        match (&result.answer, &result.status) {
+            (Some(_), Status::Art) => "▼ art".into(),
+            (Some(answer), Status::Inspect) if answer.contains('\n') => "▼ (inspect)".into(),
+            (Some(answer), Status::Inspect) => format!("{answer} (inspect)"),
+            (Some(answer), _) if answer.contains('\n') => "▼".into(),
+            (Some(answer), _) => answer.clone(),
+            (None, Status::Failed(_)) => "error".into(),
+            (None, Status::Timeout) => "timeout".into(),
+            (None, Status::Panicked(_)) => "panicked".into(),
+            (None, _) => "✖".into(),
+        }
+    }
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+/// Run the given days concurrently on all available cores. Output is buffered per day and
+/// printed in day order once all days completed, followed by a summary table.
+/// Intended for untimed runs, benchmarks of days running in parallel would disturb each other.
+pub fn run_multi_parallel(
+    solutions: &[Solution],
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    options: &RunOptions,
+) {
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+    let options = RunOptions {
+        quiet: true,
+        ..options.clone()
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    let timer = Instant::now();
+    let next = AtomicUsize::new(0);
+    let outcomes: Mutex<Vec<(Day, DayOutcome, Duration)>> = Mutex::new(vec![]);
+
+    let workers = thread::available_parallelism().map_or(1, usize::from);
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+    thread::scope(|scope| {
+        for _ in 0..workers.min(days.len()) {
+            scope.spawn(|| {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let timer = Instant::now();
+                    let outcome = run_day(solutions, year, day, &options);
+                    let elapsed = timer.elapsed();
 
-        if is_release {
-            args.push("--release");
+                    outcomes.lock().unwrap().push((day, outcome, elapsed));
+                }
+            });
         }
+    });
+
+    let wall_time = timer.elapsed();
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_unstable_by_key(|(day, _, _)| *day);
+
+    if let Some(format) = options.format {
+        let results: Vec<PartResult> = outcomes
+            .into_iter()
+            .filter_map(|(_, outcome, _)| match outcome {
+                DayOutcome::Ran(results) => Some(results),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        print!("{}", format.render(&results));
+        return;
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+    for (i, (day, outcome, _)) in outcomes.iter().enumerate() {
+        if i > 0 {
+            println!();
         }
+        print!("{}", format_day(*day, outcome));
+    }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+    println!();
+    print_summary(&outcomes, wall_time);
+}
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+fn run_day(solutions: &[Solution], year: Year, day: Day, options: &RunOptions) -> DayOutcome {
+    let Some(solution) = solutions.iter().find(|s| s.year == year && s.day == day) else {
+        return DayOutcome::NotSolved;
+    };
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    let input = match try_read_file("inputs", year, day) {
+        Ok(input) => input,
+        Err(e) => return DayOutcome::NoInput(e.to_string()),
+    };
 
-        let mut output = vec![];
+    DayOutcome::Ran(run_solution(solution, &input, options))
+}
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+/// Render the output of a day like the sequential runner prints it.
+fn format_day(day: Day, outcome: &DayOutcome) -> String {
+    let mut out = format!("{ANSI_BOLD}Day {day}{ANSI_RESET}\n------\n");
+
+    match outcome {
+        DayOutcome::NotSolved => out.push_str("Not solved.\n"),
+        DayOutcome::NoInput(e) => out.push_str(&format!("Could not read input file: {e}\n")),
+        DayOutcome::Ran(results) => {
+            for result in results {
+                let step = match result.part {
+                    PARSE_STEP => "Parse".to_string(),
+                    part => format!("Part {part}"),
+                };
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+                let line = match (result.part, &result.answer, &result.status) {
+                    (_, _, Status::Timeout) => {
+                        format!("{step}: timeout after {:.1?}", result.duration)
+                    }
+                    (_, _, Status::Panicked(e)) => format!("{step}: panicked: {e}"),
+                    (_, _, Status::Failed(e)) => format!("{step}: ✖ {e}"),
+                    (PARSE_STEP, _, _) => format!("Parse: ({:.1?})", result.duration),
+                    (part, Some(answer), Status::Decoded(art)) => format!(
+                        "Part {part}: {ANSI_BOLD}{answer}{ANSI_RESET} {ANSI_ITALIC}decoded from art{ANSI_RESET} ({:.1?})\n{art}",
+                        result.duration
+                    ),
+                    (part, Some(answer), Status::Art) => format!(
+                        "Part {part}: ▼ {ANSI_ITALIC}art{ANSI_RESET} ({:.1?})\n{answer}",
+                        result.duration
+                    ),
+                    (part, Some(answer), Status::Inspect) if answer.contains('\n') => format!(
+                        "Part {part}: ▼ {ANSI_ITALIC}needs manual inspection{ANSI_RESET} ({:.1?})\n{answer}",
+                        result.duration
+                    ),
+                    (part, Some(answer), Status::Inspect) => format!(
+                        "Part {part}: {ANSI_BOLD}{answer}{ANSI_RESET} {ANSI_ITALIC}needs manual inspection{ANSI_RESET} ({:.1?})",
+                        result.duration
+                    ),
+                    (part, Some(answer), _) if answer.contains('\n') => {
+                        format!("Part {part}: ▼ ({:.1?})\n{answer}", result.duration)
+                    }
+                    (part, Some(answer), _) => format!(
+                        "Part {part}: {ANSI_BOLD}{answer}{ANSI_RESET} ({:.1?})",
+                        result.duration
+                    ),
+                    (part, None, _) => format!("Part {part}: ✖"),
+                };
+                out.push_str(&line);
+                out.push('\n');
+            }
         }
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    out
+}
 
-        Ok(output)
+fn print_summary(outcomes: &[(Day, DayOutcome, Duration)], wall_time: Duration) {
+    let rows: Vec<Vec<String>> = outcomes
+        .iter()
+        .map(|(day, outcome, elapsed)| {
+            vec![
+                day.to_string(),
+                outcome.answer(1),
+                outcome.answer(2),
+                outcome.status().to_string(),
+                format!("{elapsed:.1?}"),
+            ]
+        })
+        .collect();
+
+    let header = ["Day", "Part 1", "Part 2", "Status", "Time"];
+
+    let lines = aligned_table(&header, &rows);
+    println!("{ANSI_BOLD}{}{ANSI_RESET}", lines[0]);
+    for line in &lines[1..] {
+        println!("{line}");
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    println!("\n{ANSI_BOLD}Total (Wall):{ANSI_RESET} {ANSI_ITALIC}{wall_time:.1?}{ANSI_RESET}");
+}
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+/// Collect the benchmark times of all solved parts of a day.
+fn timing_from_results(day: Day, results: &[PartResult]) -> Timing {
+    let mut timing = Timing::new(day);
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    for result in results.iter().filter(|r| r.status.has_answer()) {
+        let duration_str = format!("{:.1?}", result.duration);
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
+        match result.part {
+            PARSE_STEP => {
+                timing.parse = Some(duration_str);
+                timing.parse_stats.clone_from(&result.stats);
+                timing.parse_memory = result.memory;
+            }
+            1 => {
+                timing.part_1 = Some(duration_str);
+                timing.part_1_stats.clone_from(&result.stats);
+                timing.part_1_memory = result.memory;
+            }
+            2 => {
+                timing.part_2 = Some(duration_str);
+                timing.part_2_stats.clone_from(&result.stats);
+                timing.part_2_memory = result.memory;
+            }
+            _ => {}
+        }
 
-        timings
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = result.duration.as_nanos() as f64;
+        timing.total_nanos += nanos;
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+    timing
+}
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_day, timing_from_results, DayOutcome};
+    use crate::{
+        day,
+        template::results::{PartResult, Status},
+    };
+
+    #[test]
+    fn collects_timings_of_solved_parts() {
+        let results = [
+            PartResult {
+                day: day!(1),
+                part: 1,
+                answer: Some("0".into()),
+                duration: Duration::from_nanos(74),
+                samples: 100_000,
+                stats: None,
+                memory: None,
+                status: Status::Solved,
+            },
+            PartResult {
+                day: day!(1),
+                part: 2,
+                answer: Some("10".into()),
+                duration: Duration::from_millis(74),
+                samples: 99_999,
+                stats: None,
+                memory: None,
+                status: Status::Solved,
+            },
+        ];
+
+        let timing = timing_from_results(day!(1), &results);
+        assert_eq!(timing.total_nanos, 74_000_074_f64);
+        assert_eq!(timing.part_1.unwrap(), "74.0ns");
+        assert_eq!(timing.part_2.unwrap(), "74.0ms");
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn skips_unsolved_parts() {
+        let results = [PartResult {
+            day: day!(1),
+            part: 1,
+            answer: None,
+            duration: Duration::from_nanos(74),
+            samples: 1,
+            stats: None,
+            memory: None,
+            status: Status::Unsolved,
+        }];
+
+        let timing = timing_from_results(day!(1), &results);
+        assert_eq!(timing.total_nanos, 0_f64);
+        assert_eq!(timing.part_1.is_none(), true);
+        assert_eq!(timing.part_2.is_none(), true);
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            day: day!(1),
+            part,
+            answer: answer.map(Into::into),
+            duration: Duration::from_nanos(1),
+            samples: 1,
+            stats: None,
+            memory: None,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
         }
+    }
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn summarizes_day_outcomes() {
+        let solved = DayOutcome::Ran(vec![result(1, Some("42")), result(2, Some("a\nb"))]);
+        assert_eq!(solved.status(), "solved");
+        assert_eq!(solved.answer(1), "42");
+        assert_eq!(solved.answer(2), "▼");
+
+        let partial = DayOutcome::Ran(vec![result(1, Some("42")), result(2, None)]);
+        assert_eq!(partial.status(), "partial");
+        assert_eq!(partial.answer(2), "✖");
+
+        let unsolved = DayOutcome::Ran(vec![result(1, None), result(2, None)]);
+        assert_eq!(unsolved.status(), "unsolved");
+
+        let mut panicked = result(2, None);
+        panicked.status = Status::Panicked("oops".into());
+        let panicked = DayOutcome::Ran(vec![result(1, Some("42")), panicked]);
+        assert_eq!(panicked.status(), "panicked");
+        assert_eq!(panicked.answer(2), "panicked");
+
+        let mut timeout = result(1, None);
+        timeout.status = Status::Timeout;
+        let timeout = DayOutcome::Ran(vec![timeout, result(2, Some("42"))]);
+        assert_eq!(timeout.status(), "timeout");
+        assert_eq!(timeout.answer(1), "timeout");
+
+        assert_eq!(DayOutcome::NotSolved.answer(1), "-");
+    }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn summarizes_answer_kinds() {
+        let mut art = result(2, Some("#.\n.#"));
+        art.status = Status::Art;
+        let mut inspect = result(1, Some("42"));
+        inspect.status = Status::Inspect;
+        let outcome = DayOutcome::Ran(vec![inspect, art]);
+        assert_eq!(outcome.status(), "needs inspection");
+        assert_eq!(outcome.answer(1), "42 (inspect)");
+        assert_eq!(outcome.answer(2), "▼ art");
+
+        let mut failed = result(2, None);
+        failed.status = Status::Failed("no path".into());
+        let failed = DayOutcome::Ran(vec![result(1, Some("42")), failed]);
+        assert_eq!(failed.status(), "error");
+        assert_eq!(failed.answer(2), "error");
+    }
+
+    #[test]
+    fn formats_decoded_art_with_picture() {
+        let mut decoded = result(2, Some("I"));
+        decoded.status = Status::Decoded("###\n.#.\n###".into());
+        let outcome = DayOutcome::Ran(vec![decoded]);
+        assert_eq!(outcome.status(), "solved");
+        assert_eq!(outcome.answer(2), "I");
+
+        let formatted = format_day(day!(1), &outcome);
+        assert!(formatted.contains("I"));
+        assert!(formatted.contains("decoded from art"));
+        assert!(formatted.ends_with("\n###\n.#.\n###\n"));
     }
 }
@@ -1,65 +1,649 @@
 /// Encapsulates code that interacts with solution functions.
-use std::fmt::Display;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, fs, io, process, thread};
 
+use crate::template::answers::{self, Ledger};
+use crate::template::aoc_client::{self, AocClient};
+use crate::template::memory::{self, MemoryUsage};
+use crate::template::output::Format;
+use crate::template::results::{Answer, IntoAnswer, PartResult, Status, PARSE_STEP};
+use crate::template::stats::{BenchConfig, Stats};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_cli, is_interactive, try_read_file, Day, Year, ANSI_ITALIC, ANSI_RESET};
+
+/// A solution that was registered via the `solution!` macro.
+/// The main binary keeps a list of these to run days in-process.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    pub run: fn(&str, &RunOptions) -> Vec<PartResult>,
+}
+
+/// Controls how the parts of a solution are run.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub is_timed: bool,
+    pub bench: BenchConfig,
+    pub submit_part: Option<u8>,
+    pub input: InputSource,
+    /// Suppresses printing, used when the caller reports the results itself.
+    pub quiet: bool,
+    /// Maximum time a single part (or the parse step) may run, see [`run_solution`].
+    pub timeout: Option<Duration>,
+    /// Parts that are not run again when a solution is restarted after a timeout.
+    pub skip_parts: Vec<u8>,
+    /// Print all results at the end in this format instead of printing them as they are computed.
+    pub format: Option<Format>,
+}
+
+/// Where a solution binary reads its input from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The personal puzzle input in `data/YYYY/inputs`.
+    #[default]
+    Puzzle,
+    /// The example in `data/YYYY/examples`. Parts with a `DD-N.txt` example use that file instead.
+    Example,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Read the input shared by all parts.
+    pub fn read(&self, year: Year, day: Day) -> io::Result<String> {
+        match self {
+            InputSource::Puzzle => try_read_file("inputs", year, day),
+            InputSource::Example => try_read_file("examples", year, day),
+            InputSource::Path(path) => fs::read_to_string(path),
+            InputSource::Stdin => io::read_to_string(io::stdin()),
+        }
+    }
+
+    /// Read the input of a single part, if it has one that differs from the shared input.
+    pub fn read_part(&self, year: Year, day: Day, part: u8) -> Option<String> {
+        if *self != InputSource::Example {
+            return None;
+        }
+
+        let path = format!("data/{year}/examples/{day}-{part}.txt");
+        fs::read_to_string(path).ok()
+    }
+}
+
+impl RunOptions {
+    /// Parse the options from the arguments passed to a solution binary, i.e. `--time`, `--submit <part>`,
+    /// the bench settings `--warmup <n>`, `--iterations <n>` and `--budget <millis>`
+    /// the input selection `--example` or `--input <path>` (`-` reads stdin), `--timeout <seconds>`
+    /// and `--format <json|csv|markdown|plain>`.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let value_of = |name: &str| -> Option<u128> {
+            let index = args.iter().position(|x| x == name)?;
+            match args.get(index + 1).and_then(|x| x.parse().ok()) {
+                Some(value) => Some(value),
+                None => {
+                    eprintln!("Expected a number after {name}.");
+                    process::exit(1);
+                }
+            }
+        };
+
+        let defaults = BenchConfig::default();
+
+        #[allow(clippy::cast_possible_truncation)]
+        let bench = BenchConfig {
+            warmup: value_of("--warmup").unwrap_or(defaults.warmup),
+            time_budget: value_of("--budget")
+                .map_or(defaults.time_budget, |ms| Duration::from_millis(ms as u64)),
+            iterations: value_of("--iterations"),
+            ..defaults
+        };
+
+        let submit_part = args.iter().position(|x| x == "--submit").map(|index| {
+            match args.get(index + 1).and_then(|x| x.parse::<u8>().ok()) {
+                Some(part) => part,
+                None => {
+                    eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                    process::exit(1);
+                }
+            }
+        });
+
+        let input = match args.iter().position(|x| x == "--input") {
+            Some(index) => match args.get(index + 1).map(String::as_str) {
+                Some("-") => InputSource::Stdin,
+                Some(path) => InputSource::Path(PathBuf::from(path)),
+                None => {
+                    eprintln!(
+                        "Unexpected command-line input. Format: cargo solve 1 --input <path>"
+                    );
+                    process::exit(1);
+                }
+            },
+            None if args.iter().any(|x| x == "--example") => InputSource::Example,
+            None => InputSource::Puzzle,
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let timeout = value_of("--timeout").map(|secs| Duration::from_secs(secs as u64));
+
+        let format = args.iter().position(|x| x == "--format").map(|index| {
+            match args.get(index + 1).map(|x| x.parse::<Format>()) {
+                Some(Ok(format)) => format,
+                Some(Err(e)) => {
+                    eprintln!("{e}");
+                    process::exit(1);
+                }
+                None => {
+                    eprintln!("Unexpected command-line input. Format: cargo solve 1 --format json");
+                    process::exit(1);
+                }
+            }
+        });
+
+        Self {
+            is_timed: args.iter().any(|x| x == "--time"),
+            bench,
+            submit_part,
+            input,
+            quiet: format.is_some(),
+            timeout,
+            skip_parts: vec![],
+            format,
+        }
+    }
+}
+
+/// Progress of a solution that runs on a separate thread, see [`run_solution`].
+enum Progress {
+    Started(u8),
+    /// The first run of the current step finished and it is being benched.
+    Benching,
+    Finished(Box<PartResult>),
+}
+
+thread_local! {
+    static PROGRESS: RefCell<Option<Sender<Progress>>> = const { RefCell::new(None) };
+    /// Set on threads that run solutions, which report their panics as results.
+    static QUIET_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Keep the panic hook from printing panics of solutions, they are already printed as results.
+/// Panics of other threads are passed on to the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET_PANICS.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn report(progress: Progress) {
+    PROGRESS.with(|sender| {
+        if let Some(sender) = sender.borrow().as_ref() {
+            let _ = sender.send(progress);
+        }
+    });
+}
+
+/// Run all parts of a solution. Panics are reported as [`Status::Panicked`] instead of propagating.
+///
+/// If `options.timeout` is set, the solution runs on a separate thread and every part whose first
+/// run exceeds the timeout is reported as [`Status::Timeout`]. Benching a part is not limited,
+/// its duration is bounded by the bench budget.
+///
+/// A thread can't be stopped from the outside, so a part that timed out keeps running and
+/// occupies a core until the process exits. The solution is restarted to run the remaining parts,
+/// but timings taken while the part is still running are unreliable.
+pub fn run_solution(solution: &Solution, input: &str, options: &RunOptions) -> Vec<PartResult> {
+    install_panic_hook();
+
+    let Some(timeout) = options.timeout else {
+        let quiet_panics = QUIET_PANICS.replace(true);
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| (solution.run)(input, options)));
+        QUIET_PANICS.set(quiet_panics);
+
+        return match outcome {
+            Ok(results) => results,
+            Err(payload) => vec![failed_step(
+                solution.day,
+                Status::Panicked(panic_message(payload.as_ref())),
+                options,
+            )],
+        };
+    };
+
+    let mut results: Vec<PartResult> = vec![];
+
+    loop {
+        let (sender, receiver) = mpsc::channel();
+
+        let run = solution.run;
+        let input = input.to_string();
+        let thread_options = RunOptions {
+            skip_parts: results.iter().map(|r| r.part).collect(),
+            ..options.clone()
+        };
+
+        let handle = thread::spawn(move || {
+            PROGRESS.with(|progress| *progress.borrow_mut() = Some(sender));
+            QUIET_PANICS.set(true);
+            run(&input, &thread_options);
+        });
+
+        let mut current = None;
+        let mut benching = false;
+
+        let timed_out = loop {
+            let progress = if benching {
+                receiver.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                receiver.recv_timeout(timeout)
+            };
+
+            match progress {
+                Ok(Progress::Started(part)) => {
+                    current = Some(part);
+                    benching = false;
+                }
+                Ok(Progress::Benching) => benching = true,
+                Ok(Progress::Finished(result)) => {
+                    current = None;
+                    benching = false;
+                    if !results.iter().any(|r| r.part == result.part) {
+                        results.push(*result);
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => match current {
+                    Some(_) => break current,
+                    None => continue,
+                },
+                Err(RecvTimeoutError::Disconnected) => {
+                    if let Err(payload) = handle.join() {
+                        let status = Status::Panicked(panic_message(payload.as_ref()));
+                        results.push(failed_step(solution.day, status, options));
+                    }
+                    break None;
+                }
+            }
+        };
+
+        let Some(part) = timed_out else {
+            return results;
+        };
+
+        if !options.quiet {
+            println!(
+                "{}{}: timeout after {timeout:.1?}",
+                line_start(),
+                step_name(part)
+            );
+        }
+
+        let result = PartResult {
+            duration: timeout,
+            ..placeholder(solution.day, part, Status::Timeout)
+        };
+
+        if let Err(e) = result.emit() {
+            eprintln!("Failed to write result record: {e}");
+        }
+
+        results.push(result);
+
+        // without the parsed input, none of the parts can run.
+        if part == PARSE_STEP {
+            return results;
+        }
+    }
+}
+
+/// Result of a step that did not finish. Panics in the parts are caught by [`run_part`],
+/// so a solution itself only fails in its parse step.
+fn failed_step(day: Day, status: Status, options: &RunOptions) -> PartResult {
+    if !options.quiet {
+        if let Status::Panicked(message) = &status {
+            println!(
+                "{}{}: panicked: {message}",
+                line_start(),
+                step_name(PARSE_STEP)
+            );
+        }
+    }
+
+    let result = placeholder(day, PARSE_STEP, status);
+
+    if let Err(e) = result.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
+
+    result
+}
+
+/// Result of a step that was not run or did not finish.
+fn placeholder(day: Day, part: u8, status: Status) -> PartResult {
+    PartResult {
+        day,
+        part,
+        answer: None,
+        duration: Duration::ZERO,
+        samples: 0,
+        stats: None,
+        memory: None,
+        status,
+    }
+}
+
+fn step_name(part: u8) -> String {
+    match part {
+        PARSE_STEP => "Parse".into(),
+        part => format!("Part {part}"),
+    }
+}
+
+/// Extract the message of a panic payload.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown error".into())
+}
+
+/// Run a solution part. Parts can return any type that implements [`IntoAnswer`].
+pub fn run_part<I: Clone, R: IntoAnswer>(
+    func: impl Fn(I) -> R,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
+    if options.skip_parts.contains(&part) {
+        return placeholder(day, part, Status::Unsolved);
+    }
+
+    report(Progress::Started(part));
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let bench_config = options.is_timed.then_some(&options.bench);
+
+    let guarded = |input: I| panic::catch_unwind(AssertUnwindSafe(|| func(input)));
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    // intermediate output is only shown on terminals, where it is overwritten by the result.
+    let show_progress = !options.quiet && is_interactive();
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let (outcome, duration, stats, memory) =
+        run_timed(guarded, input, bench_config, show_progress, |outcome| {
+            if let (true, Ok(result)) = (show_progress, outcome) {
+                print_result(&result.to_answer(), &part_str, "");
+            }
+            outcome.is_ok()
+        });
+
+    let samples = stats.as_ref().map_or(1, |stats| stats.samples);
+
+    let outcome = outcome.map(|result| result.to_answer());
+
+    if !options.quiet {
+        match &outcome {
+            Ok(answer) => print_result(
+                answer,
+                &part_str,
+                &format_duration(&duration, samples, memory.as_ref()),
+            ),
+            Err(payload) => println!(
+                "{}{part_str}: panicked: {}",
+                line_start(),
+                panic_message(payload.as_ref())
+            ),
+        }
+
+        if let Some(stats) = &stats {
+            print_stats(stats);
+        }
     }
+
+    let (answer, status) = match outcome {
+        Ok(answer) => answer.into_parts(),
+        Err(payload) => (None, Status::Panicked(panic_message(payload.as_ref()))),
+    };
+
+    if let (Some(answer), true) = (&answer, options.submit_part == Some(part)) {
+        match status {
+            _ if options.input != InputSource::Puzzle => {
+                eprintln!("Not submitting: the answer was not computed from the puzzle input.");
+            }
+            Status::Art => {
+                eprintln!("Not submitting: the answer is a picture and has to be decoded.")
+            }
+            Status::Inspect => eprintln!("Not submitting: the answer needs manual inspection."),
+            _ => submit_result(answer, year, day, part),
+        }
+    }
+
+    let result = PartResult {
+        day,
+        part,
+        answer,
+        duration,
+        samples,
+        stats,
+        memory,
+        status,
+    };
+
+    if let Err(e) = result.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
+
+    report(Progress::Finished(Box::new(result.clone())));
+
+    result
+}
+
+/// Run the parse step of a solution that declares one, returning the parsed input for the parts.
+/// The step is timed like a part and reported with [`PARSE_STEP`] as its part number.
+pub fn run_parse<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    options: &RunOptions,
+) -> (T, PartResult) {
+    // the input still has to be parsed when the solution is restarted after a timeout.
+    if options.skip_parts.contains(&PARSE_STEP) {
+        let parsed = func(input);
+        return (parsed, placeholder(day, PARSE_STEP, Status::Solved));
+    }
+
+    report(Progress::Started(PARSE_STEP));
+
+    let bench_config = options.is_timed.then_some(&options.bench);
+
+    // intermediate output is only shown on terminals, where it is overwritten by the result.
+    let show_progress = !options.quiet && is_interactive();
+
+    let (parsed, duration, stats, memory) =
+        run_timed(func, input, bench_config, show_progress, |_| {
+            if show_progress {
+                print!("Parse:");
+            }
+            true
+        });
+
+    let samples = stats.as_ref().map_or(1, |stats| stats.samples);
+
+    if !options.quiet {
+        print_parse("Parse", &duration, samples, memory.as_ref(), stats.as_ref());
+    }
+
+    let result = PartResult {
+        day,
+        part: PARSE_STEP,
+        answer: None,
+        duration,
+        samples,
+        stats,
+        memory,
+        status: Status::Solved,
+    };
+
+    if let Err(e) = result.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
+
+    report(Progress::Finished(Box::new(result.clone())));
+
+    (parsed, result)
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Parse the input of a single part that has its own example, see [`InputSource::Example`].
+/// Timed like [`run_parse`] and counted towards the part, so a panic or timeout fails only this part.
+/// Returns the result of the part if it could not be parsed.
+pub fn run_part_parse<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> Result<T, Box<PartResult>> {
+    if options.skip_parts.contains(&part) {
+        return Err(Box::new(placeholder(day, part, Status::Unsolved)));
+    }
+
+    report(Progress::Started(part));
+
+    let step = format!("Parse {part}");
+
+    let bench_config = options.is_timed.then_some(&options.bench);
+
+    let guarded = |input: I| panic::catch_unwind(AssertUnwindSafe(|| func(input)));
+
+    // intermediate output is only shown on terminals, where it is overwritten by the result.
+    let show_progress = !options.quiet && is_interactive();
+
+    let (outcome, duration, stats, memory) =
+        run_timed(guarded, input, bench_config, show_progress, |outcome| {
+            if show_progress {
+                print!("{step}:");
+            }
+            outcome.is_ok()
+        });
+
+    let samples = stats.as_ref().map_or(1, |stats| stats.samples);
+
+    let message = match outcome {
+        Ok(parsed) => {
+            if !options.quiet {
+                print_parse(&step, &duration, samples, memory.as_ref(), stats.as_ref());
+            }
+            return Ok(parsed);
+        }
+        Err(payload) => panic_message(payload.as_ref()),
+    };
+
+    if !options.quiet {
+        println!("{}{step}: panicked: {message}", line_start());
+    }
+
+    let result = PartResult {
+        duration,
+        memory,
+        ..placeholder(day, part, Status::Panicked(message))
+    };
+
+    if let Err(e) = result.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
+
+    let result = Box::new(result);
+    report(Progress::Finished(result.clone()));
+
+    Err(result)
+}
+
+fn print_parse(
+    step: &str,
+    duration: &Duration,
+    samples: u128,
+    memory: Option<&MemoryUsage>,
+    stats: Option<&Stats>,
+) {
+    print!("{}", line_start());
+    println!("{step}:{}", format_duration(duration, samples, memory));
+
+    if let Some(stats) = stats {
+        print_stats(stats);
+    }
+}
+
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. if not timed, the function is executed once.
+///  2. if timed, the function is benched according to the [`BenchConfig`] and the median sample is reported.
+///
+/// The `hook` is called with the result of the first run and returns whether benching should continue.
+/// Heap usage is measured for the first run only. Progress of the bench is printed if `show_progress` is set.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    bench_config: Option<&BenchConfig>,
+    show_progress: bool,
+    hook: impl Fn(&T) -> bool,
+) -> (T, Duration, Option<Stats>, Option<MemoryUsage>) {
+    let input_clone = input.clone();
     let timer = Instant::now();
-    let result = {
-        let input = input.clone();
-
+    let (result, memory) = memory::measure(|| {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
-    };
+        func(input_clone)
+    });
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let should_bench = hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
+    let stats = bench_config.filter(|_| should_bench).and_then(|config| {
+        report(Progress::Benching);
+        bench(func, input, &base_time, config, show_progress)
+    });
+    let duration = stats.as_ref().map_or(base_time, |stats| stats.median);
 
-    (result, run.0, run.1)
+    (result, duration, stats, memory)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+    show_progress: bool,
+) -> Option<Stats> {
+    if show_progress {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    for _ in 0..config.warmup {
+        black_box(func(black_box(input.clone())));
+    }
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let bench_iterations = config.iterations_for(base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -71,99 +655,114 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    Stats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+fn format_duration(duration: &Duration, samples: u128, memory: Option<&MemoryUsage>) -> String {
+    let memory = memory.map_or_else(String::new, |memory| format!(" · {memory}"));
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
     if samples == 1 {
-        format!(" ({duration:.1?})")
+        format!(" ({duration:.1?}{memory})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(" ({duration:.1?} @ {samples} samples{memory})")
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
-    let is_intermediate_result = duration_str.is_empty();
+fn print_stats(stats: &Stats) {
+    println!(
+        "        {ANSI_ITALIC}min {:.1?} · max {:.1?} · σ {:.1?} · p95 {:.1?} · p99 {:.1?} · {} outliers{ANSI_RESET}",
+        stats.min, stats.max, stats.std_dev, stats.p95, stats.p99, stats.outliers
+    );
+}
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
-                }
-            } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
-                if is_intermediate_result {
-                    print!("{str}");
-                } else {
-                    print!("\r");
-                    println!("{str}");
-                }
-            }
-        }
-        None => {
-            if is_intermediate_result {
-                print!("{part}: ✖");
-            } else {
-                print!("\r");
-                println!("{part}: ✖             ");
-            }
-        }
+/// Moves the cursor back to overwrite an intermediate result, which is only printed to terminals.
+fn line_start() -> &'static str {
+    if is_interactive() {
+        "\r"
+    } else {
+        ""
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+fn print_result(answer: &Answer, part: &str, duration_str: &str) {
+    let is_intermediate_result = duration_str.is_empty();
 
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+    let (line, body) = match answer {
+        Answer::Value(value) => (
+            format!("{part}: {ANSI_BOLD}{value}{ANSI_RESET}{duration_str}"),
+            None,
+        ),
+        Answer::Art {
+            art,
+            decoded: Some(decoded),
+        } => (
+            format!("{part}: {ANSI_BOLD}{decoded}{ANSI_RESET} {ANSI_ITALIC}decoded from art{ANSI_RESET}{duration_str}"),
+            Some(art),
+        ),
+        Answer::Art { art, decoded: None } => (
+            format!("{part}: ▼ {ANSI_ITALIC}art{ANSI_RESET}{duration_str}"),
+            Some(art),
+        ),
+        Answer::Inspect(value) if value.contains('\n') => (
+            format!("{part}: ▼ {ANSI_ITALIC}needs manual inspection{ANSI_RESET}{duration_str}"),
+            Some(value),
+        ),
+        Answer::Inspect(value) => (
+            format!("{part}: {ANSI_BOLD}{value}{ANSI_RESET} {ANSI_ITALIC}needs manual inspection{ANSI_RESET}{duration_str}"),
+            None,
+        ),
+        Answer::Error(message) => (format!("{part}: ✖ {message}{duration_str}"), None),
+        Answer::Unsolved if is_intermediate_result => (format!("{part}: ✖"), None),
+        Answer::Unsolved => (format!("{part}: ✖             "), None),
+    };
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if is_intermediate_result {
+        print!("{line}");
+    } else {
+        print!("{}", line_start());
+        println!("{line}");
+        if let Some(body) = body {
+            println!("{body}");
+        }
     }
+}
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+/// Submit one part of the solution with the built-in client.
+/// Answers that the ledger in `data/YYYY/answers` knows to be wrong are not sent.
+/// Uses aoc-cli instead if `AOC_USE_CLI` is set.
+fn submit_result(result: &str, year: Year, day: Day, part: u8) {
+    let mut ledger = Ledger::read(year, day).unwrap_or_else(|e| {
+        eprintln!("failed to read answer ledger: {e}");
         process::exit(1);
-    };
+    });
 
-    if part_submit != part {
-        return None;
+    if let Err(refusal) = ledger.check(part, result, answers::now()) {
+        eprintln!("Not submitting: {refusal}");
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    if aoc_client::use_cli() {
+        if aoc_cli::check().is_err() {
+            eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+            process::exit(1);
+        }
+
+        println!("Submitting result via aoc-cli...");
+        if let Err(e) = aoc_cli::submit(year, day, part, result) {
+            eprintln!("failed to call aoc-cli: {e}");
+        }
+        return;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    match AocClient::from_env(year).and_then(|client| client.submit(day, part, result)) {
+        Ok(verdict) => {
+            println!("{verdict}");
+            ledger.record(part, result, verdict, answers::now());
+            if let Err(e) = ledger.store(year, day) {
+                eprintln!("failed to store answer ledger: {e}");
+            }
+        }
+        Err(e) => eprintln!("failed to submit answer: {e}"),
+    }
 }
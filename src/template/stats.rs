@@ -0,0 +1,232 @@
+/// Summary statistics over the samples collected while benching a solution part.
+use std::{collections::HashMap, time::Duration};
+
+use tinyjson::JsonValue;
+
+/// Configures how many samples are collected when benching a solution part.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed iterations that run before sampling starts.
+    pub warmup: u128,
+    /// Approximate time spent sampling, used to derive the iteration count.
+    pub time_budget: Duration,
+    /// Fixed number of iterations. Overrides `time_budget` if set.
+    pub iterations: Option<u128>,
+    pub min_iterations: u128,
+    pub max_iterations: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            time_budget: Duration::from_secs(1),
+            iterations: None,
+            min_iterations: 10,
+            max_iterations: 10_000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Number of samples to collect for a function that took `base_time` on its first run.
+    pub fn iterations_for(&self, base_time: &Duration) -> u128 {
+        self.iterations.unwrap_or_else(|| {
+            (self.time_budget.as_nanos() / base_time.as_nanos().max(10))
+                .clamp(self.min_iterations, self.max_iterations)
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    /// Number of samples outside of Tukey's fences (1.5 × IQR beyond the quartiles).
+    pub outliers: u128,
+}
+
+impl Stats {
+    /// Computes statistics over a set of samples, returns [`None`] if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        #[allow(clippy::cast_precision_loss)]
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let n = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Some(Self {
+            samples: sorted.len() as u128,
+            mean: from_nanos(mean),
+            median: from_nanos(percentile(&nanos, 50.0)),
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            std_dev: from_nanos(variance.sqrt()),
+            p95: from_nanos(percentile(&nanos, 95.0)),
+            p99: from_nanos(percentile(&nanos, 99.0)),
+            outliers: nanos.iter().filter(|&&x| x < low || x > high).count() as u128,
+        })
+    }
+}
+
+/// Linear interpolation between the closest ranks of a sorted slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Stats> for JsonValue {
+    fn from(value: &Stats) -> Self {
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = |d: Duration| JsonValue::Number(d.as_nanos() as f64);
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        let samples = value.samples as f64;
+        map.insert("samples".into(), JsonValue::Number(samples));
+        map.insert("mean_nanos".into(), nanos(value.mean));
+        map.insert("median_nanos".into(), nanos(value.median));
+        map.insert("min_nanos".into(), nanos(value.min));
+        map.insert("max_nanos".into(), nanos(value.max));
+        map.insert("std_dev_nanos".into(), nanos(value.std_dev));
+        map.insert("p95_nanos".into(), nanos(value.p95));
+        map.insert("p99_nanos".into(), nanos(value.p99));
+
+        #[allow(clippy::cast_precision_loss)]
+        let outliers = value.outliers as f64;
+        map.insert("outliers".into(), JsonValue::Number(outliers));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Stats {
+            samples: number("samples")? as u128,
+            mean: from_nanos(number("mean_nanos")?),
+            median: from_nanos(number("median_nanos")?),
+            min: from_nanos(number("min_nanos")?),
+            max: from_nanos(number("max_nanos")?),
+            std_dev: from_nanos(number("std_dev_nanos")?),
+            p95: from_nanos(number("p95_nanos")?),
+            p99: from_nanos(number("p99_nanos")?),
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{BenchConfig, Stats};
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_millis(x)).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = Stats::from_samples(&millis(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(5));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1_414_214));
+        assert_eq!(stats.p95, Duration::from_micros(4_800));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn median_is_robust_against_outliers() {
+        let stats = Stats::from_samples(&millis(&[10, 10, 11, 10, 12, 10, 500])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.max, Duration::from_millis(500));
+        assert_eq!(stats.outliers, 1);
+        assert!(stats.mean > Duration::from_millis(70));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = Stats::from_samples(&millis(&[1, 2, 3, 100])).unwrap();
+        let json = JsonValue::from(&stats);
+        assert_eq!(Stats::try_from(&json).unwrap(), stats);
+    }
+
+    #[test]
+    fn derives_iterations_from_budget() {
+        let config = BenchConfig::default();
+        assert_eq!(config.iterations_for(&Duration::from_millis(1)), 1000);
+        assert_eq!(config.iterations_for(&Duration::from_secs(1)), 10);
+        assert_eq!(config.iterations_for(&Duration::from_nanos(1)), 10_000);
+    }
+
+    #[test]
+    fn prefers_fixed_iterations() {
+        let config = BenchConfig {
+            iterations: Some(42),
+            ..BenchConfig::default()
+        };
+        assert_eq!(config.iterations_for(&Duration::from_secs(1)), 42);
+    }
+}
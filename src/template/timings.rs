@@ -1,19 +1,75 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::{memory::MemoryUsage, results::PARSE_STEP, stats::Stats, Day, Year};
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
+    /// Only present for solutions that declare a separate parse step.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub parse_stats: Option<Stats>,
+    pub part_1_stats: Option<Stats>,
+    pub part_2_stats: Option<Stats>,
+    /// Only present if the counting allocator was installed, see [`crate::template::memory`].
+    pub parse_memory: Option<MemoryUsage>,
+    pub part_1_memory: Option<MemoryUsage>,
+    pub part_2_memory: Option<MemoryUsage>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// A day without any timings.
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            parse_stats: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            parse_memory: None,
+            part_1_memory: None,
+            part_2_memory: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// Duration of the parse step or a part in nanoseconds.
+    /// Uses the median if stats were recorded and falls back to parsing the formatted duration otherwise.
+    pub fn step_nanos(&self, step: u8) -> Option<f64> {
+        let (formatted, stats) = match step {
+            PARSE_STEP => (&self.parse, &self.parse_stats),
+            1 => (&self.part_1, &self.part_1_stats),
+            2 => (&self.part_2, &self.part_2_stats),
+            _ => return None,
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        match stats {
+            Some(stats) => Some(stats.median.as_nanos() as f64),
+            None => parse_duration(formatted.as_deref()?),
+        }
+    }
+}
+
+/// Parse a duration that was formatted with `{:.1?}` to nanoseconds.
+fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse_to_float = |postfix: &str| s.split(postfix).next()?.trim().parse::<f64>().ok();
+
+    match s {
+        s if s.contains("ns") => parse_to_float("ns"),
+        s if s.contains("µs") => parse_to_float("µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float("ms").map(|x| x * 1_000_000_f64),
+        _ => parse_to_float("s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -22,16 +78,16 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        let s = fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings from the JSON file of a year. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        let s = fs::read_to_string(get_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from);
 
@@ -58,7 +114,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
@@ -74,6 +130,10 @@ impl Timings {
     }
 }
 
+fn get_path(year: Year) -> String {
+    format!("./data/{year}/timings.json")
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
@@ -140,6 +200,42 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "parse".into(),
+            match &value.parse {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        for (key, stats) in [
+            ("parse_stats", &value.parse_stats),
+            ("part_1_stats", &value.part_1_stats),
+            ("part_2_stats", &value.part_2_stats),
+        ] {
+            map.insert(
+                key.into(),
+                match stats {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        for (key, memory) in [
+            ("parse_memory", &value.parse_memory),
+            ("part_1_memory", &value.part_1_memory),
+            ("part_2_memory", &value.part_2_memory),
+        ] {
+            map.insert(
+                key.into(),
+                match memory {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -173,10 +269,36 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // `parse`, stats and memory are optional to stay compatible with timings stored by older versions.
+        let parse = match json.get("parse") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be null or string.")?,
+            ),
+            _ => None,
+        };
+
+        let stats = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => Stats::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
+        let memory = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => MemoryUsage::try_from(v).map(Some),
+            _ => Ok(None),
+        };
+
         Ok(Timing {
             day,
+            parse: parse.cloned(),
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            parse_stats: stats("parse_stats")?,
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
+            parse_memory: memory("parse_memory")?,
+            part_1_memory: memory("part_1_memory")?,
+            part_2_memory: memory("part_2_memory")?,
             total_nanos,
         })
     }
@@ -194,22 +316,21 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
-                    part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -285,42 +406,81 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
-                    part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![Timing::new(day!(1))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod step_nanos {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{
+                stats::Stats,
+                timings::{Timing, PARSE_STEP},
+            },
+        };
+
+        #[test]
+        fn parses_formatted_durations() {
+            let timing = Timing {
+                parse: Some("74.1ns".into()),
+                part_1: Some("2.5µs".into()),
+                part_2: Some("1.2s".into()),
+                ..Timing::new(day!(1))
+            };
+
+            assert_eq!(timing.step_nanos(PARSE_STEP), Some(74.1));
+            assert_eq!(timing.step_nanos(1), Some(2500.0));
+            assert_eq!(timing.step_nanos(2), Some(1_200_000_000.0));
+        }
+
+        #[test]
+        fn prefers_median_from_stats() {
+            let stats = Stats::from_samples(&[
+                Duration::from_millis(1),
+                Duration::from_millis(2),
+                Duration::from_millis(30),
+            ]);
+
+            let timing = Timing {
+                part_1: Some("11.0ms".into()),
+                part_1_stats: stats,
+                ..Timing::new(day!(1))
+            };
+
+            assert_eq!(timing.step_nanos(PARSE_STEP), None);
+            assert_eq!(timing.step_nanos(1), Some(2_000_000.0));
+            assert_eq!(timing.step_nanos(2), None);
         }
     }
 
@@ -336,12 +496,7 @@ mod tests {
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(3))],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -356,12 +511,7 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![Timing::new(day!(2))],
             };
             let merged = timings.merge(&other);
 
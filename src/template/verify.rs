@@ -0,0 +1,486 @@
+/// Checks the answers of solved days against the known answers in `data/YYYY/answers.json`,
+/// falling back to the accepted answers in the ledger.
+use std::{collections::HashMap, fs, io, str::FromStr, time::Duration};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    answers::Ledger,
+    results::{PartResult, Status, PARSE_STEP},
+    runner::{run_solution, RunOptions, Solution},
+    try_read_file, Day, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+/// The known answers of a day, recorded with `cargo verify --accept`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KnownAnswer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Known answers of all days of a year, committed so that every solved day can be verified.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KnownAnswers {
+    pub data: Vec<KnownAnswer>,
+}
+
+impl KnownAnswers {
+    /// Read the known answers of a year. If the file is not present, returns no answers.
+    pub fn read(year: Year) -> Result<Self, String> {
+        let Ok(s) = fs::read_to_string(get_path(year)) else {
+            return Ok(KnownAnswers::default());
+        };
+
+        let json = JsonValue::from_str(&s).or(Err("not valid JSON file."))?;
+        KnownAnswers::try_from(&json)
+    }
+
+    pub fn store(&self, year: Year) -> Result<(), io::Error> {
+        let json = JsonValue::from(self)
+            .format()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+
+        fs::create_dir_all(format!("./data/{year}"))?;
+        fs::write(get_path(year), json + "\n")
+    }
+
+    /// The known answer of a part, if any.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let known = self.data.iter().find(|k| k.day == day)?;
+        match part {
+            1 => known.part_1.as_deref(),
+            2 => known.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Record `answer` as the known answer of a part, replacing a previous one.
+    pub fn accept(&mut self, day: Day, part: u8, answer: &str) {
+        let index = match self.data.binary_search_by_key(&day, |k| k.day) {
+            Ok(index) => index,
+            Err(index) => {
+                self.data.insert(
+                    index,
+                    KnownAnswer {
+                        day,
+                        part_1: None,
+                        part_2: None,
+                    },
+                );
+                index
+            }
+        };
+
+        let known = &mut self.data[index];
+        let slot = if part == 1 {
+            &mut known.part_1
+        } else {
+            &mut known.part_2
+        };
+        *slot = Some(answer.into());
+    }
+}
+
+fn get_path(year: Year) -> String {
+    format!("./data/{year}/answers.json")
+}
+
+/// Result of checking one part against its accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No known or accepted answer is stored for this part.
+    Missing,
+    /// The input of the day could not be read.
+    NoInput,
+    /// The part (or the parse step) failed, panicked or timed out. Contains its status.
+    Error(String),
+}
+
+impl Outcome {
+    fn label(&self) -> &'static str {
+        match self {
+            Outcome::Pass => "pass",
+            Outcome::Fail { .. } => "fail",
+            Outcome::Missing => "missing",
+            Outcome::NoInput => "no input",
+            Outcome::Error(_) => "error",
+        }
+    }
+}
+
+/// The answer a part is checked against: the known answer if recorded, otherwise the answer
+/// that was accepted when submitting.
+pub fn expected_answer<'a>(
+    known: &'a KnownAnswers,
+    ledger: &'a Ledger,
+    day: Day,
+    part: u8,
+) -> Option<&'a str> {
+    known.get(day, part).or_else(|| ledger.correct_answer(part))
+}
+
+/// Compare the result of a part to its expected answer. A part that did not finish fails
+/// even if no answer is expected.
+pub fn check_part(expected: Option<&str>, result: Option<&PartResult>) -> Outcome {
+    if let Some(status @ (Status::Failed(_) | Status::Timeout | Status::Panicked(_))) =
+        result.map(|r| &r.status)
+    {
+        return Outcome::Error(status.to_string());
+    }
+
+    let Some(expected) = expected else {
+        return Outcome::Missing;
+    };
+
+    let actual = result
+        .filter(|r| r.status.has_answer())
+        .and_then(|r| r.answer.clone());
+
+    if actual.as_deref() == Some(expected) {
+        Outcome::Pass
+    } else {
+        Outcome::Fail {
+            expected: expected.into(),
+            actual,
+        }
+    }
+}
+
+/// The result a part is checked with: its own result, or the parse step's if parsing did not finish.
+fn part_result(results: &[PartResult], part: u8) -> Option<&PartResult> {
+    results
+        .iter()
+        .find(|r| r.part == part)
+        .or_else(|| results.iter().find(|r| r.part == PARSE_STEP))
+}
+
+/// Run a day against its input, isolating panics and stopping parts that exceed `timeout`.
+/// Returns `None` if the day has no solution.
+fn run_day(
+    solutions: &[Solution],
+    year: Year,
+    day: Day,
+    timeout: Option<Duration>,
+) -> Option<Result<Vec<PartResult>, io::Error>> {
+    let solution = solutions.iter().find(|s| s.year == year && s.day == day)?;
+
+    let input = match try_read_file("inputs", year, day) {
+        Ok(input) => input,
+        Err(e) => return Some(Err(e)),
+    };
+
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+    let options = RunOptions {
+        timeout,
+        ..RunOptions::default()
+    };
+    let results = run_solution(solution, &input, &options);
+    println!();
+
+    Some(Ok(results))
+}
+
+/// Run the given days against their inputs and check both parts.
+pub fn verify_days(
+    solutions: &[Solution],
+    year: Year,
+    days: &[Day],
+    timeout: Option<Duration>,
+) -> Vec<(Day, [Outcome; 2])> {
+    let known = KnownAnswers::read(year).unwrap_or_else(|e| {
+        eprintln!("failed to read known answers: {e}");
+        KnownAnswers::default()
+    });
+
+    days.iter()
+        .filter_map(|&day| {
+            let Ok(results) = run_day(solutions, year, day, timeout)? else {
+                return Some((day, [Outcome::NoInput, Outcome::NoInput]));
+            };
+
+            let ledger = Ledger::read(year, day).unwrap_or_else(|e| {
+                eprintln!("failed to read answer ledger of day {day}: {e}");
+                Ledger::default()
+            });
+
+            let outcomes = [1, 2].map(|part| {
+                check_part(
+                    expected_answer(&known, &ledger, day, part),
+                    part_result(&results, part),
+                )
+            });
+
+            Some((day, outcomes))
+        })
+        .collect()
+}
+
+/// Run the given days against their inputs and record their answers as known answers.
+/// Parts without an answer keep their previous known answer. Returns the recorded answers.
+pub fn accept_days(
+    solutions: &[Solution],
+    year: Year,
+    days: &[Day],
+    timeout: Option<Duration>,
+) -> Result<Vec<(Day, u8, String)>, String> {
+    let mut known = KnownAnswers::read(year)?;
+    let mut accepted = Vec::new();
+
+    for &day in days {
+        let results = match run_day(solutions, year, day, timeout) {
+            Some(Ok(results)) => results,
+            Some(Err(e)) => {
+                eprintln!("failed to read input of day {day}: {e}");
+                continue;
+            }
+            None => continue,
+        };
+
+        for result in results {
+            let Some(answer) = result.answer.filter(|_| result.status.has_answer()) else {
+                continue;
+            };
+            if result.part == 1 || result.part == 2 {
+                known.accept(day, result.part, &answer);
+                accepted.push((day, result.part, answer));
+            }
+        }
+    }
+
+    known.store(year).map_err(|e| e.to_string())?;
+    Ok(accepted)
+}
+
+/// Print a table of all outcomes followed by details about failures. Returns whether all checks passed.
+pub fn print_report(outcomes: &[(Day, [Outcome; 2])]) -> bool {
+    println!("{ANSI_BOLD}Day  Part 1    Part 2{ANSI_RESET}");
+
+    for (day, [part_1, part_2]) in outcomes {
+        println!("{day}   {:<8}  {}", part_1.label(), part_2.label());
+    }
+
+    let mut passed = true;
+
+    for (day, parts) in outcomes {
+        for (part, outcome) in parts.iter().enumerate() {
+            match outcome {
+                Outcome::Fail { expected, actual } => {
+                    passed = false;
+                    println!(
+                        "{ANSI_BOLD}Day {day} Part {}:{ANSI_RESET} expected `{expected}`, got `{}`",
+                        part + 1,
+                        actual.as_deref().unwrap_or("✖")
+                    );
+                }
+                Outcome::Error(status) => {
+                    passed = false;
+                    println!(
+                        "{ANSI_BOLD}Day {day} Part {}:{ANSI_RESET} {status}",
+                        part + 1
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    passed
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&KnownAnswers> for JsonValue {
+    fn from(value: &KnownAnswers) -> Self {
+        let data = value
+            .data
+            .iter()
+            .map(|known| {
+                let mut map: HashMap<String, JsonValue> = HashMap::new();
+                map.insert("day".into(), JsonValue::String(known.day.to_string()));
+                for (key, answer) in [("part_1", &known.part_1), ("part_2", &known.part_2)] {
+                    map.insert(
+                        key.into(),
+                        answer.clone().map_or(JsonValue::Null, JsonValue::String),
+                    );
+                }
+                JsonValue::Object(map)
+            })
+            .collect();
+
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+        map.insert("data".into(), JsonValue::Array(data));
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for KnownAnswers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let data = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected known answers to be a JSON object.")?
+            .get("data")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected known answers to have an array `data`.")?;
+
+        let mut known = KnownAnswers::default();
+
+        for entry in data {
+            let json = entry
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("Expected known answer to be a JSON object.")?;
+
+            let day = json
+                .get("day")
+                .and_then(|v| v.get::<String>())
+                .and_then(|v| v.parse::<Day>().ok())
+                .ok_or("Expected known answer to have a valid `day`.")?;
+
+            for (part, key) in [(1, "part_1"), (2, "part_2")] {
+                if let Some(answer) = json.get(key).and_then(|v| v.get::<String>()) {
+                    known.accept(day, part, answer);
+                }
+            }
+        }
+
+        Ok(known)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{check_part, expected_answer, part_result, KnownAnswers, Outcome};
+    use crate::{
+        day,
+        template::{
+            answers::Ledger,
+            aoc_client::Verdict,
+            results::{PartResult, Status, PARSE_STEP},
+        },
+    };
+
+    fn result(answer: Option<&str>) -> PartResult {
+        PartResult {
+            day: day!(1),
+            part: 1,
+            answer: answer.map(Into::into),
+            duration: Duration::from_nanos(1),
+            samples: 1,
+            stats: None,
+            memory: None,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+        }
+    }
+
+    fn ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.record(1, "41", Verdict::TooLow, 0);
+        ledger.record(1, "42", Verdict::Correct, 100);
+        ledger
+    }
+
+    #[test]
+    fn passes_accepted_answers() {
+        assert_eq!(
+            check_part(ledger().correct_answer(1), Some(&result(Some("42")))),
+            Outcome::Pass
+        );
+    }
+
+    #[test]
+    fn fails_changed_answers() {
+        assert_eq!(
+            check_part(ledger().correct_answer(1), Some(&result(Some("41")))),
+            Outcome::Fail {
+                expected: "42".into(),
+                actual: Some("41".into())
+            }
+        );
+        assert_eq!(
+            check_part(ledger().correct_answer(1), Some(&result(None))),
+            Outcome::Fail {
+                expected: "42".into(),
+                actual: None
+            }
+        );
+    }
+
+    #[test]
+    fn fails_parts_that_did_not_finish() {
+        let panicked = PartResult {
+            part: PARSE_STEP,
+            status: Status::Panicked("boom".into()),
+            ..result(None)
+        };
+        let results = [panicked];
+
+        for part in [1, 2] {
+            assert_eq!(
+                check_part(ledger().correct_answer(part), part_result(&results, part)),
+                Outcome::Error("panicked: boom".into())
+            );
+        }
+
+        let timeout = PartResult {
+            status: Status::Timeout,
+            ..result(None)
+        };
+        assert_eq!(
+            check_part(None, Some(&timeout)),
+            Outcome::Error("timeout".into())
+        );
+    }
+
+    #[test]
+    fn reports_missing_answers() {
+        assert_eq!(
+            check_part(ledger().correct_answer(2), Some(&result(Some("42")))),
+            Outcome::Missing
+        );
+    }
+
+    #[test]
+    fn prefers_known_answers_over_ledger() {
+        let mut known = KnownAnswers::default();
+        known.accept(day!(1), 2, "7");
+        known.accept(day!(1), 1, "43");
+
+        let ledger = ledger();
+        assert_eq!(expected_answer(&known, &ledger, day!(1), 1), Some("43"));
+        assert_eq!(expected_answer(&known, &ledger, day!(1), 2), Some("7"));
+        assert_eq!(expected_answer(&known, &ledger, day!(2), 1), Some("42"));
+        assert_eq!(expected_answer(&known, &ledger, day!(2), 2), None);
+    }
+
+    #[test]
+    fn roundtrips_known_answers() {
+        let mut known = KnownAnswers::default();
+        known.accept(day!(3), 1, "abc");
+        known.accept(day!(1), 1, "1");
+        known.accept(day!(1), 1, "2");
+
+        assert_eq!(known.data.len(), 2);
+        assert_eq!(known.get(day!(1), 1), Some("2"));
+
+        let json = JsonValue::from(&known);
+        assert_eq!(KnownAnswers::try_from(&json).unwrap(), known);
+    }
+}
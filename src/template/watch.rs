@@ -0,0 +1,221 @@
+/// Polling file watcher and helpers for `cargo solve DD --watch`.
+/// Polling keeps this portable and does not depend on a file system notification service.
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// A watched location: a single file, all files in a folder that start with a prefix,
+/// or all files in a folder and its subfolders except the `exclude`d ones.
+#[derive(Clone, Debug)]
+pub enum Target {
+    File(PathBuf),
+    Prefixed { dir: PathBuf, prefix: String },
+    Tree { dir: PathBuf, exclude: Vec<PathBuf> },
+}
+
+/// Fingerprint of a file, compared between polls.
+type Fingerprint = (Option<SystemTime>, u64);
+
+pub struct Watcher {
+    targets: Vec<Target>,
+    snapshot: HashMap<PathBuf, Fingerprint>,
+}
+
+impl Watcher {
+    pub fn new(targets: Vec<Target>) -> Self {
+        let snapshot = scan(&targets);
+        Self { targets, snapshot }
+    }
+
+    /// Returns `true` if any watched file was created, modified or removed since the last call.
+    pub fn poll(&mut self) -> bool {
+        let snapshot = scan(&self.targets);
+        let changed = snapshot != self.snapshot;
+        self.snapshot = snapshot;
+        changed
+    }
+}
+
+fn scan(targets: &[Target]) -> HashMap<PathBuf, Fingerprint> {
+    let mut snapshot = HashMap::new();
+
+    for target in targets {
+        match target {
+            Target::File(path) => {
+                if let Some(fingerprint) = fingerprint(path) {
+                    snapshot.insert(path.clone(), fingerprint);
+                }
+            }
+            Target::Prefixed { dir, prefix } => {
+                let Ok(entries) = fs::read_dir(dir) else {
+                    continue;
+                };
+
+                for entry in entries.filter_map(Result::ok) {
+                    let path = entry.path();
+                    let matches = entry
+                        .file_name()
+                        .to_str()
+                        .is_some_and(|name| name.starts_with(prefix.as_str()));
+
+                    if let Some(fingerprint) = fingerprint(&path).filter(|_| matches) {
+                        snapshot.insert(path, fingerprint);
+                    }
+                }
+            }
+            Target::Tree { dir, exclude } => scan_tree(dir, exclude, &mut snapshot),
+        }
+    }
+
+    snapshot
+}
+
+fn scan_tree(dir: &Path, exclude: &[PathBuf], snapshot: &mut HashMap<PathBuf, Fingerprint>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+
+        if exclude.contains(&path) {
+            continue;
+        }
+
+        if path.is_dir() {
+            scan_tree(&path, exclude, snapshot);
+        } else if let Some(fingerprint) = fingerprint(&path) {
+            snapshot.insert(path, fingerprint);
+        }
+    }
+}
+
+fn fingerprint(path: &Path) -> Option<Fingerprint> {
+    let metadata = fs::metadata(path).ok()?;
+    metadata
+        .is_file()
+        .then(|| (metadata.modified().ok(), metadata.len()))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Outcome of a `cargo test` run.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: Vec<String>,
+}
+
+/// Collect the test results from the output of `cargo test`.
+/// Returns [`None`] if no tests ran, e.g. because the build failed.
+pub fn parse_test_output(output: &str) -> Option<TestSummary> {
+    let mut summary = TestSummary::default();
+    let mut has_results = false;
+
+    for line in output.lines() {
+        if let Some(rest) = line.strip_prefix("test ") {
+            if rest.ends_with(" ... ok") {
+                summary.passed += 1;
+            } else if let Some(name) = rest.strip_suffix(" ... FAILED") {
+                summary.failed.push(name.to_string());
+            }
+        }
+
+        if line.starts_with("test result:") {
+            has_results = true;
+        }
+    }
+
+    has_results.then_some(summary)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{parse_test_output, Target, TestSummary, Watcher};
+
+    #[test]
+    fn detects_changes() {
+        let dir = env::temp_dir().join(format!("aoc-watch-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("01.txt"), "a").unwrap();
+
+        let mut watcher = Watcher::new(vec![Target::Prefixed {
+            dir: dir.clone(),
+            prefix: "01".into(),
+        }]);
+        assert!(!watcher.poll());
+
+        fs::write(dir.join("01.txt"), "ab").unwrap();
+        assert!(watcher.poll());
+        assert!(!watcher.poll());
+
+        fs::write(dir.join("02.txt"), "ignored").unwrap();
+        assert!(!watcher.poll());
+
+        fs::write(dir.join("01-2.txt"), "new").unwrap();
+        assert!(watcher.poll());
+
+        fs::remove_file(dir.join("01.txt")).unwrap();
+        assert!(watcher.poll());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn detects_changes_in_tree() {
+        let dir = env::temp_dir().join(format!("aoc-watch-tree-test-{}", process::id()));
+        fs::create_dir_all(dir.join("template")).unwrap();
+        fs::create_dir_all(dir.join("bin")).unwrap();
+        fs::write(dir.join("grid.rs"), "a").unwrap();
+
+        let mut watcher = Watcher::new(vec![Target::Tree {
+            dir: dir.clone(),
+            exclude: vec![dir.join("bin")],
+        }]);
+        assert!(!watcher.poll());
+
+        fs::write(dir.join("grid.rs"), "ab").unwrap();
+        assert!(watcher.poll());
+
+        fs::write(dir.join("template").join("runner.rs"), "new").unwrap();
+        assert!(watcher.poll());
+
+        fs::write(dir.join("bin").join("2024_01.rs"), "ignored").unwrap();
+        assert!(!watcher.poll());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parses_test_output() {
+        let output = "
+running 2 tests
+test tests::test_part_one ... ok
+test tests::test_part_two ... FAILED
+
+failures:
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+        assert_eq!(
+            parse_test_output(output),
+            Some(TestSummary {
+                passed: 1,
+                failed: vec!["tests::test_part_two".into()]
+            })
+        );
+    }
+
+    #[test]
+    fn handles_build_failures() {
+        assert_eq!(parse_test_output("error[E0308]: mismatched types"), None);
+    }
+}
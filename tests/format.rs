@@ -0,0 +1,26 @@
+use std::process::Command;
+
+use tinyjson::JsonValue;
+
+/// Formatted output is meant to be piped into other tools, so stdout must only contain the results.
+#[test]
+fn timed_json_output_is_valid_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_2024_01"))
+        .args([
+            "--example",
+            "--time",
+            "--iterations",
+            "3",
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let json: JsonValue = stdout.parse().unwrap_or_else(|e| panic!("{e}: {stdout}"));
+    let results = json.get::<Vec<JsonValue>>().unwrap();
+    assert_eq!(results.len(), 2);
+}